@@ -1,6 +1,20 @@
 use std::marker::PhantomData;
 
-use cosmwasm_std::{Decimal, DivideByZeroError, OverflowError, Uint128};
+use cosmwasm_std::{
+    CheckedFromRatioError, ConversionOverflowError, Decimal, Decimal256, DivideByZeroError,
+    OverflowError, OverflowOperation, Uint128, Uint256, Uint512, Uint64,
+};
+
+use crate::{denom_name, MonetaryError, Rounding};
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub struct AmountU64<T>(
+    pub(crate) Uint64,
+    #[cfg_attr(feature = "serde", serde(skip))] PhantomData<T>,
+);
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(transparent))]
@@ -11,177 +25,323 @@ pub struct AmountU128<T>(
     #[cfg_attr(feature = "serde", serde(skip))] PhantomData<T>,
 );
 
-impl<T> AmountU128<T> {
-    #[inline]
-    pub const fn new(amount: Uint128) -> Self {
-        AmountU128(amount, PhantomData)
-    }
-
-    #[inline]
-    pub const fn u128(&self) -> u128 {
-        self.0.u128()
-    }
-
-    #[inline]
-    pub const fn uint128(&self) -> Uint128 {
-        self.0
-    }
-
-    #[inline]
-    pub const fn zero() -> Self {
-        Self::new(Uint128::zero())
-    }
-
-    #[must_use]
-    #[inline]
-    pub const fn is_zero(&self) -> bool {
-        self.0.is_zero()
-    }
-
-    pub fn checked_add(self, other: Self) -> Result<Self, OverflowError> {
-        Ok(Self::new(self.0.checked_add(other.0)?))
-    }
-
-    pub fn checked_sub(self, other: Self) -> Result<Self, OverflowError> {
-        Ok(Self::new(self.0.checked_sub(other.0)?))
-    }
-
-    pub fn checked_mul(self, other: Self) -> Result<Self, OverflowError> {
-        Ok(Self::new(self.0.checked_mul(other.0)?))
-    }
-
-    pub fn checked_div(self, other: Self) -> Result<Self, DivideByZeroError> {
-        Ok(Self::new(self.0.checked_div(other.0)?))
-    }
-
-    #[must_use = "this returns the result of the operation, without modifying the original"]
-    pub fn saturating_add(self, other: Self) -> Self {
-        Self::new(self.0.saturating_add(other.0))
-    }
-
-    #[must_use = "this returns the result of the operation, without modifying the original"]
-    pub fn saturating_sub(self, other: Self) -> Self {
-        Self::new(self.0.saturating_sub(other.0))
-    }
-
-    #[must_use = "this returns the result of the operation, without modifying the original"]
-    pub fn saturating_mul(self, other: Self) -> Self {
-        Self::new(self.0.saturating_mul(other.0))
-    }
-
-    #[must_use = "this returns the result of the operation, without modifying the original"]
-    pub const fn abs_diff(self, other: Self) -> Self {
-        Self::new(self.0.abs_diff(other.0))
-    }
-
-    pub fn dec_mul_floor(self, dec: Decimal) -> Self {
-        Self::new(self.0.mul_floor(dec))
-    }
-
-    pub fn dec_mul_ceil(self, dec: Decimal) -> Self {
-        Self::new(self.0.mul_ceil(dec))
-    }
-
-    pub fn dec_div_floor(self, dec: Decimal) -> Result<Self, DivideByZeroError> {
-        Ok(Self::new(self.0.div_floor(dec)))
-    }
-
-    pub fn dec_div_ceil(self, dec: Decimal) -> Result<Self, DivideByZeroError> {
-        Ok(Self::new(self.0.div_ceil(dec)))
-    }
-}
-
-impl<T> std::ops::Add for AmountU128<T> {
-    type Output = Self;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        Self::new(self.0 + rhs.0)
-    }
-}
-
-impl<T> std::ops::Sub for AmountU128<T> {
-    type Output = Self;
-
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self::new(self.0 - rhs.0)
-    }
-}
-
-impl<T> std::ops::Mul for AmountU128<T> {
-    type Output = Self;
-
-    fn mul(self, rhs: Self) -> Self::Output {
-        Self::new(self.0 * rhs.0)
-    }
-}
-
-impl<T> std::ops::Div for AmountU128<T> {
-    type Output = Self;
-
-    fn div(self, rhs: Self) -> Self::Output {
-        Self::new(self.0 / rhs.0)
-    }
-}
-
-impl<T> std::ops::AddAssign for AmountU128<T> {
-    fn add_assign(&mut self, rhs: Self) {
-        self.0 += rhs.0;
-    }
-}
-
-impl<T> std::ops::SubAssign for AmountU128<T> {
-    fn sub_assign(&mut self, rhs: Self) {
-        self.0 -= rhs.0;
-    }
-}
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub struct AmountU256<T>(
+    pub(crate) Uint256,
+    #[cfg_attr(feature = "serde", serde(skip))] PhantomData<T>,
+);
 
-impl<T> std::ops::MulAssign for AmountU128<T> {
-    fn mul_assign(&mut self, rhs: Self) {
-        self.0 *= rhs.0;
-    }
+/// Implements the API shared by every fixed-width amount type.
+///
+/// Amounts of the same currency can be added and subtracted, but only scaled by dimensionless
+/// factors: multiplying two amounts yields an untyped wider integer, and dividing two amounts
+/// yields a dimensionless ratio.
+macro_rules! impl_amount {
+    ($amount:ident, $uint:ident, $uint_fn:ident, $wide:ty, $dec:ident, [$($scalar:ty),*]) => {
+        impl<T> $amount<T> {
+            #[inline]
+            pub const fn new(amount: $uint) -> Self {
+                $amount(amount, PhantomData)
+            }
+
+            #[inline]
+            pub const fn $uint_fn(&self) -> $uint {
+                self.0
+            }
+
+            #[inline]
+            pub const fn zero() -> Self {
+                Self::new($uint::zero())
+            }
+
+            #[must_use]
+            #[inline]
+            pub const fn is_zero(&self) -> bool {
+                self.0.is_zero()
+            }
+
+            pub fn checked_add(self, other: Self) -> Result<Self, OverflowError> {
+                Ok(Self::new(self.0.checked_add(other.0)?))
+            }
+
+            pub fn checked_sub(self, other: Self) -> Result<Self, OverflowError> {
+                Ok(Self::new(self.0.checked_sub(other.0)?))
+            }
+
+            /// Multiplies by a dimensionless factor.
+            pub fn checked_mul(self, factor: impl Into<$uint>) -> Result<Self, OverflowError> {
+                Ok(Self::new(self.0.checked_mul(factor.into())?))
+            }
+
+            /// Divides by a dimensionless divisor, rounding down.
+            pub fn checked_div(self, divisor: impl Into<$uint>) -> Result<Self, DivideByZeroError> {
+                Ok(Self::new(self.0.checked_div(divisor.into())?))
+            }
+
+            /// Multiplies by a decimal, rounding as specified. Errors if the result overflows.
+            pub fn dec_mul(self, dec: $dec, rounding: Rounding) -> Result<Self, MonetaryError> {
+                let scale = Uint256::from(10u8).pow($dec::DECIMAL_PLACES);
+                let product = rounding.mul_div(self.0.into(), dec.atomics().into(), scale);
+                product
+                    .try_into()
+                    .map(Self::new)
+                    .map_err(|_| MonetaryError::Overflow {
+                        operation: OverflowOperation::Mul,
+                        denom: denom_name::<T>(),
+                        lhs: self.to_string(),
+                        rhs: dec.to_string(),
+                    })
+            }
+
+            /// Multiplies by a decimal, rounding down.
+            ///
+            /// # Panics
+            /// Panics if the result overflows. Use `dec_mul` to handle this case.
+            pub fn dec_mul_floor(self, dec: $dec) -> Self {
+                self.dec_mul(dec, Rounding::Floor)
+                    .unwrap_or_else(|err| panic!("{err}"))
+            }
+
+            /// Multiplies by a decimal, rounding up.
+            ///
+            /// # Panics
+            /// Panics if the result overflows. Use `dec_mul` to handle this case.
+            pub fn dec_mul_ceil(self, dec: $dec) -> Self {
+                self.dec_mul(dec, Rounding::Ceil)
+                    .unwrap_or_else(|err| panic!("{err}"))
+            }
+
+            /// Divides by a decimal, rounding as specified. Errors if `dec` is zero or the result
+            /// overflows.
+            pub fn dec_div(self, dec: $dec, rounding: Rounding) -> Result<Self, MonetaryError> {
+                if dec.is_zero() {
+                    return Err(MonetaryError::DivideByZero {
+                        denom: denom_name::<T>(),
+                        lhs: self.to_string(),
+                    });
+                }
+                let scale = Uint256::from(10u8).pow($dec::DECIMAL_PLACES);
+                let quotient = rounding.mul_div(self.0.into(), scale, dec.atomics().into());
+                quotient
+                    .try_into()
+                    .map(Self::new)
+                    .map_err(|_| MonetaryError::Overflow {
+                        operation: OverflowOperation::Mul,
+                        denom: denom_name::<T>(),
+                        lhs: self.to_string(),
+                        rhs: format!("1 / {dec}"),
+                    })
+            }
+
+            pub fn dec_div_floor(self, dec: $dec) -> Result<Self, MonetaryError> {
+                self.dec_div(dec, Rounding::Floor)
+            }
+
+            pub fn dec_div_ceil(self, dec: $dec) -> Result<Self, MonetaryError> {
+                self.dec_div(dec, Rounding::Ceil)
+            }
+
+            /// Returns the dimensionless ratio `self / other`. Errors if `other` is zero or the
+            /// ratio is out of range. There is deliberately no `Div` operator between amounts,
+            /// as both cases are reachable with valid amounts.
+            pub fn checked_ratio(self, other: Self) -> Result<$dec, CheckedFromRatioError> {
+                $dec::checked_from_ratio(self.0, other.0)
+            }
+
+            /// Multiplies two amounts into an untyped integer wide enough to never overflow.
+            /// The product of two amounts of `T` is not itself an amount of `T`.
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub fn full_mul(self, other: Self) -> $wide {
+                self.0.full_mul(other.0)
+            }
+
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub fn saturating_add(self, other: Self) -> Self {
+                Self::new(self.0.saturating_add(other.0))
+            }
+
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub fn saturating_sub(self, other: Self) -> Self {
+                Self::new(self.0.saturating_sub(other.0))
+            }
+
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub fn saturating_mul(self, factor: impl Into<$uint>) -> Self {
+                Self::new(self.0.saturating_mul(factor.into()))
+            }
+
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn abs_diff(self, other: Self) -> Self {
+                Self::new(self.0.abs_diff(other.0))
+            }
+        }
+
+        impl<T> std::ops::Add for $amount<T> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                Self::new(self.0 + rhs.0)
+            }
+        }
+
+        impl<T> std::ops::Sub for $amount<T> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self::new(self.0 - rhs.0)
+            }
+        }
+
+        impl<T> std::ops::Mul<$dec> for $amount<T> {
+            type Output = Self;
+
+            /// Scales by a decimal factor, rounding down. See also `dec_mul_ceil`.
+            ///
+            /// # Panics
+            /// Panics if the result overflows.
+            fn mul(self, rhs: $dec) -> Self::Output {
+                self.dec_mul_floor(rhs)
+            }
+        }
+
+        impl<T> std::ops::AddAssign for $amount<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl<T> std::ops::SubAssign for $amount<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                self.0 -= rhs.0;
+            }
+        }
+
+        $(
+            impl<T> std::ops::Mul<$scalar> for $amount<T> {
+                type Output = Self;
+
+                fn mul(self, rhs: $scalar) -> Self::Output {
+                    Self::new(self.0 * $uint::from(rhs))
+                }
+            }
+
+            impl<T> std::ops::Div<$scalar> for $amount<T> {
+                type Output = Self;
+
+                fn div(self, rhs: $scalar) -> Self::Output {
+                    Self::new(self.0 / $uint::from(rhs))
+                }
+            }
+
+            impl<T> std::ops::MulAssign<$scalar> for $amount<T> {
+                fn mul_assign(&mut self, rhs: $scalar) {
+                    self.0 *= $uint::from(rhs);
+                }
+            }
+
+            impl<T> std::ops::DivAssign<$scalar> for $amount<T> {
+                fn div_assign(&mut self, rhs: $scalar) {
+                    self.0 /= $uint::from(rhs);
+                }
+            }
+        )*
+
+        impl<T> std::fmt::Display for $amount<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl<T> From<$amount<T>> for String {
+            fn from(val: $amount<T>) -> Self {
+                val.0.to_string()
+            }
+        }
+
+        impl<T: PartialEq> PartialOrd for $amount<T> {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.0.cmp(&other.0))
+            }
+        }
+
+        impl<T: Eq> Ord for $amount<T> {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        impl<T: Default> Default for $amount<T> {
+            fn default() -> Self {
+                Self::new($uint::default())
+            }
+        }
+    };
 }
 
-impl<T> std::ops::DivAssign for AmountU128<T> {
-    fn div_assign(&mut self, rhs: Self) {
-        self.0 /= rhs.0;
-    }
+/// Implements lossless widening and checked narrowing between two amount types.
+macro_rules! impl_amount_conversion {
+    ($narrow:ident, $wide:ident) => {
+        impl<T> From<$narrow<T>> for $wide<T> {
+            fn from(val: $narrow<T>) -> Self {
+                $wide::new(val.0.into())
+            }
+        }
+
+        impl<T> TryFrom<$wide<T>> for $narrow<T> {
+            type Error = ConversionOverflowError;
+
+            fn try_from(val: $wide<T>) -> Result<Self, Self::Error> {
+                Ok($narrow::new(val.0.try_into()?))
+            }
+        }
+    };
 }
 
-impl<T> std::fmt::Display for AmountU128<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{}", self.0)
-    }
-}
+impl_amount!(AmountU64, Uint64, uint64, Uint128, Decimal, [Uint64, u64]);
+impl_amount!(
+    AmountU128,
+    Uint128,
+    uint128,
+    Uint256,
+    Decimal,
+    [Uint128, u128]
+);
+impl_amount!(
+    AmountU256,
+    Uint256,
+    uint256,
+    Uint512,
+    Decimal256,
+    [Uint256, u128]
+);
 
-impl<T> From<AmountU128<T>> for String {
-    fn from(val: AmountU128<T>) -> Self {
-        val.0.to_string()
-    }
-}
+impl_amount_conversion!(AmountU64, AmountU128);
+impl_amount_conversion!(AmountU64, AmountU256);
+impl_amount_conversion!(AmountU128, AmountU256);
 
-impl<T: PartialEq> PartialOrd for AmountU128<T> {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.0.cmp(&other.0))
-    }
-}
-
-impl<T: Eq> Ord for AmountU128<T> {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.0.cmp(&other.0)
+impl<T> AmountU64<T> {
+    #[inline]
+    pub const fn u64(&self) -> u64 {
+        self.0.u64()
     }
 }
 
-impl<T: Default> Default for AmountU128<T> {
-    fn default() -> Self {
-        Self::new(Uint128::default())
+impl<T> AmountU128<T> {
+    #[inline]
+    pub const fn u128(&self) -> u128 {
+        self.0.u128()
     }
 }
 
 #[cfg(test)]
 mod test {
+    use cosmwasm_std::{CheckedFromRatioError, Decimal, Uint128, Uint256, Uint64};
     use monetary_macros::denom;
 
-    use crate::AmountU128;
+    use crate::{AmountU128, AmountU256, AmountU64, MonetaryError, Rounding};
 
     #[denom]
     pub struct Denom;
@@ -194,5 +354,118 @@ mod test {
 
         let b: AmountU128<Denom> = serde_json_wasm::from_str(&serialized).unwrap();
         assert_eq!(a, b);
+
+        let a = AmountU256::<Denom>::new(Uint256::MAX);
+        let serialized = serde_json_wasm::to_string(&a).unwrap();
+        assert_eq!(serialized, format!(r#""{}""#, Uint256::MAX));
+
+        let b: AmountU256<Denom> = serde_json_wasm::from_str(&serialized).unwrap();
+        assert_eq!(a, b);
+
+        let a = AmountU64::<Denom>::new(12345u64.into());
+        let serialized = serde_json_wasm::to_string(&a).unwrap();
+        assert_eq!(serialized, r#""12345""#);
+
+        let b: AmountU64<Denom> = serde_json_wasm::from_str(&serialized).unwrap();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn cross_width_conversions() {
+        let small = AmountU64::<Denom>::new(Uint64::MAX);
+        let mid = AmountU128::from(small);
+        let wide = AmountU256::from(mid);
+        assert_eq!(mid.u128(), u64::MAX as u128);
+        assert_eq!(AmountU128::try_from(wide).unwrap(), mid);
+        assert_eq!(AmountU64::try_from(wide).unwrap(), small);
+
+        let too_wide = AmountU256::<Denom>::from(AmountU128::new(Uint128::MAX)) + wide;
+        AmountU128::try_from(too_wide).unwrap_err();
+        AmountU64::try_from(mid + AmountU128::new(1u128.into())).unwrap_err();
+    }
+
+    #[test]
+    fn dec_mul_overflow() {
+        let max = AmountU64::<Denom>::new(Uint64::MAX);
+        assert_eq!(max.dec_mul_floor(Decimal::percent(50)).u64(), u64::MAX / 2);
+        assert_eq!(
+            max.dec_mul_ceil(Decimal::percent(50)).u64(),
+            u64::MAX / 2 + 1
+        );
+        assert!(matches!(
+            max.dec_mul(Decimal::percent(200), Rounding::Floor)
+                .unwrap_err(),
+            MonetaryError::Overflow { .. }
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "Mul overflow")]
+    fn dec_mul_floor_overflow_panics() {
+        AmountU64::<Denom>::new(Uint64::MAX).dec_mul_floor(Decimal::percent(200));
+    }
+
+    #[test]
+    fn scalar_arithmetic() {
+        let a = AmountU128::<Denom>::new(Uint128::new(300));
+        let b = AmountU128::<Denom>::new(Uint128::new(200));
+
+        assert_eq!(a * 2u128, AmountU128::new(Uint128::new(600)));
+        assert_eq!(a / Uint128::new(7), AmountU128::new(Uint128::new(42)));
+        assert_eq!(a * Decimal::percent(33), AmountU128::new(Uint128::new(99)));
+        assert_eq!(a.checked_ratio(b).unwrap(), Decimal::percent(150));
+        assert_eq!(
+            a.checked_ratio(AmountU128::zero()).unwrap_err(),
+            CheckedFromRatioError::DivideByZero
+        );
+        let huge = AmountU128::<Denom>::new(Uint128::new(10u128.pow(21)));
+        assert_eq!(
+            huge.checked_ratio(AmountU128::new(Uint128::one()))
+                .unwrap_err(),
+            CheckedFromRatioError::Overflow
+        );
+        assert_eq!(a.full_mul(b), Uint256::from(60_000u128));
+
+        assert_eq!(
+            a.dec_mul(Decimal::permille(5), Rounding::HalfEven).unwrap(),
+            AmountU128::new(Uint128::new(2))
+        );
+        assert_eq!(
+            a.dec_mul(Decimal::permille(5), Rounding::HalfUp).unwrap(),
+            AmountU128::new(Uint128::new(2))
+        );
+        assert_eq!(
+            a.dec_mul(Decimal::permille(5), Rounding::Floor).unwrap(),
+            AmountU128::new(Uint128::new(1))
+        );
+        AmountU128::<Denom>::new(Uint128::MAX)
+            .dec_mul(Decimal::percent(101), Rounding::Floor)
+            .unwrap_err();
+
+        assert_eq!(
+            a.dec_div_ceil(Decimal::percent(70)).unwrap(),
+            AmountU128::new(Uint128::new(429))
+        );
+        assert_eq!(
+            a.dec_div_floor(Decimal::zero()).unwrap_err(),
+            MonetaryError::DivideByZero {
+                denom: std::any::type_name::<Denom>().to_string(),
+                lhs: "300".to_string(),
+            }
+        );
+        assert!(matches!(
+            AmountU128::<Denom>::new(Uint128::MAX)
+                .dec_div_floor(Decimal::permille(1))
+                .unwrap_err(),
+            MonetaryError::Overflow { .. }
+        ));
+        assert!(AmountU64::<Denom>::new(Uint64::MAX)
+            .dec_div_ceil(Decimal::percent(99))
+            .is_err());
+
+        let mut c = a;
+        c *= 3u128;
+        c /= 9u128;
+        assert_eq!(c, AmountU128::new(Uint128::new(100)));
     }
 }
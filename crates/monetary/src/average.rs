@@ -0,0 +1,298 @@
+use cosmwasm_std::{Decimal, Decimal256, Env, OverflowOperation, Timestamp};
+
+use crate::{zero_rate, MonetaryError, Rate};
+
+/// A time-weighted average price accumulator for a [`Rate`].
+///
+/// The accumulator tracks the integral of the rate over time. Take a [`TwapSnapshot`] at the
+/// start of a window and compare against it later with [`Twap::average_since`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = ""))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Twap<A, B> {
+    cumulative: Decimal256,
+    last_rate: Rate<A, B>,
+    last_time: Timestamp,
+}
+
+/// The state of a [`Twap`] accumulator at a point in time.
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TwapSnapshot {
+    pub cumulative: Decimal256,
+    pub time: Timestamp,
+}
+
+impl<A, B> Twap<A, B> {
+    /// Starts accumulating from `rate` in the current block.
+    pub fn new(rate: Rate<A, B>, env: &Env) -> Self {
+        Twap {
+            cumulative: Decimal256::zero(),
+            last_rate: rate,
+            last_time: env.block.time,
+        }
+    }
+
+    pub fn last_rate(&self) -> &Rate<A, B> {
+        &self.last_rate
+    }
+
+    /// Records a new rate, effective from the current block.
+    /// Errors if the accumulator overflows.
+    pub fn record(&mut self, rate: Rate<A, B>, env: &Env) -> Result<(), MonetaryError> {
+        self.cumulative = self.cumulative_at(env.block.time)?;
+        self.last_rate = rate;
+        self.last_time = self.last_time.max(env.block.time);
+        Ok(())
+    }
+
+    /// Returns the state of the accumulator in the current block.
+    pub fn snapshot(&self, env: &Env) -> Result<TwapSnapshot, MonetaryError> {
+        Ok(TwapSnapshot {
+            cumulative: self.cumulative_at(env.block.time)?,
+            time: env.block.time,
+        })
+    }
+
+    /// Returns the time-weighted average rate between `snapshot` and the current block, or
+    /// the last recorded rate if no time has passed.
+    pub fn average_since(
+        &self,
+        snapshot: &TwapSnapshot,
+        env: &Env,
+    ) -> Result<Rate<A, B>, MonetaryError> {
+        let elapsed = env
+            .block
+            .time
+            .seconds()
+            .saturating_sub(snapshot.time.seconds());
+        if elapsed == 0 {
+            return Rate::new(self.last_rate.rate()).ok_or_else(zero_rate::<A, B>);
+        }
+        let cumulative = self.cumulative_at(env.block.time)?;
+        let average =
+            cumulative.saturating_sub(snapshot.cumulative) / Decimal256::from_ratio(elapsed, 1u64);
+        let average = Decimal::try_from(average).map_err(|_| MonetaryError::Overflow {
+            operation: OverflowOperation::Add,
+            denom: String::new(),
+            lhs: cumulative.to_string(),
+            rhs: snapshot.cumulative.to_string(),
+        })?;
+        Rate::new(average).ok_or_else(zero_rate::<A, B>)
+    }
+
+    fn cumulative_at(&self, time: Timestamp) -> Result<Decimal256, MonetaryError> {
+        let elapsed = time.seconds().saturating_sub(self.last_time.seconds());
+        let rate = Decimal256::from(self.last_rate.rate());
+        rate.checked_mul(Decimal256::from_ratio(elapsed, 1u64))
+            .and_then(|area| self.cumulative.checked_add(area))
+            .map_err(|_| MonetaryError::Overflow {
+                operation: OverflowOperation::Add,
+                denom: String::new(),
+                lhs: self.cumulative.to_string(),
+                rhs: format!("{rate} * {elapsed}s"),
+            })
+    }
+}
+
+/// An exponential moving average of a [`Rate`], where an observation's weight halves every
+/// `half_life` seconds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = ""))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Ema<A, B> {
+    rate: Rate<A, B>,
+    half_life: u64,
+    last_time: Timestamp,
+}
+
+impl<A, B> Ema<A, B> {
+    /// Starts averaging from `rate` in the current block. A `half_life` of zero disables
+    /// smoothing, so the average is always the last recorded rate.
+    pub fn new(rate: Rate<A, B>, half_life: u64, env: &Env) -> Self {
+        Ema {
+            rate,
+            half_life,
+            last_time: env.block.time,
+        }
+    }
+
+    pub fn rate(&self) -> &Rate<A, B> {
+        &self.rate
+    }
+
+    pub fn half_life(&self) -> u64 {
+        self.half_life
+    }
+
+    /// Blends `rate` into the average, weighting the previous average by how long ago it was
+    /// last updated. Errors if the average rounds to zero.
+    pub fn record(&mut self, rate: Rate<A, B>, env: &Env) -> Result<(), MonetaryError> {
+        let elapsed = env
+            .block
+            .time
+            .seconds()
+            .saturating_sub(self.last_time.seconds());
+        let weight = decay(elapsed, self.half_life);
+        let average = Decimal256::from(self.rate.rate()) * weight
+            + Decimal256::from(rate.rate()) * (Decimal256::one() - weight);
+        // A weighted average of two decimals is no larger than the larger of them.
+        let average = Decimal::try_from(average).unwrap();
+        self.rate = Rate::new(average).ok_or_else(zero_rate::<A, B>)?;
+        self.last_time = self.last_time.max(env.block.time);
+        Ok(())
+    }
+}
+
+/// Returns `0.5^(elapsed / half_life)`, or zero when `half_life` is zero.
+///
+/// Whole half-lives halve the weight exactly. The remaining fraction is expanded in binary,
+/// where the k-th digit contributes a factor of `0.5^(2^-k)`, found by repeated square roots.
+/// This makes the weight independent of how often the average is updated.
+fn decay(elapsed: u64, half_life: u64) -> Decimal256 {
+    if half_life == 0 {
+        return Decimal256::zero();
+    }
+    let halvings = elapsed / half_life;
+    // Beyond this the weight is below the smallest representable decimal.
+    if halvings >= 64 {
+        return Decimal256::zero();
+    }
+    let mut weight = Decimal256::from_ratio(1u64, 1u128 << halvings);
+    let mut remainder = (elapsed % half_life) as u128;
+    let mut root = Decimal256::percent(50);
+    // Further digits change the weight by less than the decimal precision.
+    for _ in 0..64 {
+        if remainder == 0 {
+            break;
+        }
+        root = root.sqrt();
+        remainder *= 2;
+        if remainder >= half_life as u128 {
+            remainder -= half_life as u128;
+            weight *= root;
+        }
+    }
+    weight
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::{testing::mock_env, Decimal, Decimal256, Env};
+
+    use crate::test::{rate, Atom, Usd};
+    use crate::{Ema, Twap};
+
+    fn after(env: &Env, seconds: u64) -> Env {
+        let mut env = env.clone();
+        env.block.time = env.block.time.plus_seconds(seconds);
+        env
+    }
+
+    #[test]
+    fn twap() {
+        let start = mock_env();
+        let mut twap = Twap::new(rate(1000), &start);
+        let snapshot = twap.snapshot(&start).unwrap();
+        assert_eq!(twap.average_since(&snapshot, &start).unwrap(), rate(1000));
+
+        // 10 for 30s, then 12 for 10s
+        let env = after(&start, 30);
+        twap.record(rate(1200), &env).unwrap();
+        let env = after(&env, 10);
+        assert_eq!(twap.average_since(&snapshot, &env).unwrap(), rate(1050));
+
+        // A later window only sees the later rate
+        let later = twap.snapshot(&env).unwrap();
+        let env = after(&env, 5);
+        assert_eq!(twap.average_since(&later, &env).unwrap(), rate(1200));
+    }
+
+    #[test]
+    fn ema() {
+        let start = mock_env();
+        let mut ema = Ema::new(rate(1000), 60, &start);
+
+        // One half-life weights the old average and the new rate equally
+        let env = after(&start, 60);
+        ema.record(rate(2000), &env).unwrap();
+        assert_eq!(ema.rate(), &rate(1500));
+
+        // No time passing leaves the average unchanged
+        ema.record(rate(100_000), &env).unwrap();
+        assert_eq!(ema.rate(), &rate(1500));
+
+        // A long gap replaces the average
+        let env = after(&env, 60 * 100);
+        ema.record(rate(900), &env).unwrap();
+        assert_eq!(ema.rate(), &rate(900));
+
+        let mut instant = Ema::new(rate(1000), 0, &start);
+        instant.record(rate(500), &start).unwrap();
+        assert_eq!(instant.rate(), &rate(500));
+    }
+
+    #[test]
+    fn ema_is_independent_of_update_frequency() {
+        let start = mock_env();
+        for step in [1, 6, 7, 30, 60] {
+            let mut ema = Ema::new(rate(1000), 60, &start);
+            let mut env = start.clone();
+            for _ in 0..(60 / step) {
+                env = after(&env, step);
+                ema.record(rate(2000), &env).unwrap();
+            }
+            // Catch up on the seconds left over when the step doesn't divide the half-life
+            if 60 % step != 0 {
+                env = after(&env, 60 % step);
+                ema.record(rate(2000), &env).unwrap();
+            }
+            let error = ema.rate().rate().abs_diff(Decimal::percent(1500));
+            assert!(
+                error < Decimal::from_ratio(1u64, 10u64.pow(12)),
+                "{step}: {error}"
+            );
+        }
+    }
+
+    #[test]
+    fn decay() {
+        use super::decay;
+
+        assert_eq!(decay(0, 60), Decimal256::one());
+        assert_eq!(decay(60, 60), Decimal256::percent(50));
+        assert_eq!(decay(120, 60), Decimal256::percent(25));
+        assert_eq!(decay(30, 60), Decimal256::percent(50).sqrt());
+        assert_eq!(
+            decay(90, 60),
+            Decimal256::percent(50) * Decimal256::percent(50).sqrt()
+        );
+        assert_eq!(decay(60 * 64, 60), Decimal256::zero());
+        assert_eq!(decay(60, 0), Decimal256::zero());
+
+        // 10 updates of 6s each decay as much as a single update after 60s
+        let step = decay(6, 60);
+        let error = step.pow(10).abs_diff(Decimal256::percent(50));
+        assert!(
+            error < Decimal256::from_ratio(1u64, 10u64.pow(15)),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn serialization() {
+        let env = mock_env();
+        let twap = Twap::new(rate(1000), &env);
+        let serialized = serde_json_wasm::to_string(&twap).unwrap();
+        let deserialized: Twap<Usd, Atom> = serde_json_wasm::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, twap);
+
+        let ema = Ema::new(rate(1000), 60, &env);
+        let serialized = serde_json_wasm::to_string(&ema).unwrap();
+        let deserialized: Ema<Usd, Atom> = serde_json_wasm::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, ema);
+    }
+}
@@ -0,0 +1,303 @@
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+use cosmwasm_std::{OverflowOperation, Uint128, Uint256, Uint64};
+
+use crate::{denom_name, AmountU128, AmountU256, AmountU64, CheckedCoin, MonetaryError};
+
+/// A value whose arithmetic operators never panic.
+///
+/// Every operator on a `Checked` value returns another `Checked` value, so expressions such as
+/// `a.checked() + b - c` can be chained freely. The first failing operation is recorded, and
+/// surfaced as a [`MonetaryError`] by [`Checked::result`].
+#[derive(Debug, PartialEq)]
+pub struct Checked<A>(Result<A, MonetaryError>);
+
+impl<A> Checked<A> {
+    pub fn new(value: A) -> Self {
+        Checked(Ok(value))
+    }
+
+    pub fn result(self) -> Result<A, MonetaryError> {
+        self.0
+    }
+
+    fn and_then(self, op: impl FnOnce(A) -> Result<A, MonetaryError>) -> Self {
+        Checked(self.0.and_then(op))
+    }
+
+    fn zip_with(self, rhs: Self, op: impl FnOnce(A, A) -> Result<A, MonetaryError>) -> Self {
+        Checked(self.0.and_then(|lhs| op(lhs, rhs.0?)))
+    }
+}
+
+impl<A> From<Checked<A>> for Result<A, MonetaryError> {
+    fn from(val: Checked<A>) -> Self {
+        val.0
+    }
+}
+
+macro_rules! impl_checked_amount {
+    ($amount:ident, $uint:ident) => {
+        impl<T> $amount<T> {
+            /// Switches to non-panicking arithmetic. See [`Checked`].
+            pub fn checked(self) -> Checked<Self> {
+                Checked::new(self)
+            }
+        }
+
+        impl<T> Add<$amount<T>> for Checked<$amount<T>> {
+            type Output = Self;
+
+            fn add(self, rhs: $amount<T>) -> Self::Output {
+                self.and_then(|lhs| {
+                    lhs.0
+                        .checked_add(rhs.0)
+                        .map($amount::new)
+                        .map_err(|_| MonetaryError::Overflow {
+                            operation: OverflowOperation::Add,
+                            denom: denom_name::<T>(),
+                            lhs: lhs.to_string(),
+                            rhs: rhs.to_string(),
+                        })
+                })
+            }
+        }
+
+        impl<T> Sub<$amount<T>> for Checked<$amount<T>> {
+            type Output = Self;
+
+            fn sub(self, rhs: $amount<T>) -> Self::Output {
+                self.and_then(|lhs| {
+                    lhs.0
+                        .checked_sub(rhs.0)
+                        .map($amount::new)
+                        .map_err(|_| MonetaryError::Underflow {
+                            denom: denom_name::<T>(),
+                            lhs: lhs.to_string(),
+                            rhs: rhs.to_string(),
+                        })
+                })
+            }
+        }
+
+        impl<T, S: Into<$uint>> Mul<S> for Checked<$amount<T>> {
+            type Output = Self;
+
+            fn mul(self, rhs: S) -> Self::Output {
+                let rhs: $uint = rhs.into();
+                self.and_then(|lhs| {
+                    lhs.0
+                        .checked_mul(rhs)
+                        .map($amount::new)
+                        .map_err(|_| MonetaryError::Overflow {
+                            operation: OverflowOperation::Mul,
+                            denom: denom_name::<T>(),
+                            lhs: lhs.to_string(),
+                            rhs: rhs.to_string(),
+                        })
+                })
+            }
+        }
+
+        impl<T, S: Into<$uint>> Div<S> for Checked<$amount<T>> {
+            type Output = Self;
+
+            fn div(self, rhs: S) -> Self::Output {
+                let rhs: $uint = rhs.into();
+                self.and_then(|lhs| {
+                    lhs.0
+                        .checked_div(rhs)
+                        .map($amount::new)
+                        .map_err(|_| MonetaryError::DivideByZero {
+                            denom: denom_name::<T>(),
+                            lhs: lhs.to_string(),
+                        })
+                })
+            }
+        }
+
+        impl<T, S: Into<$uint>> MulAssign<S> for Checked<$amount<T>> {
+            fn mul_assign(&mut self, rhs: S) {
+                let lhs = std::mem::replace(self, Checked::new($amount::zero()));
+                *self = lhs * rhs;
+            }
+        }
+
+        impl<T, S: Into<$uint>> DivAssign<S> for Checked<$amount<T>> {
+            fn div_assign(&mut self, rhs: S) {
+                let lhs = std::mem::replace(self, Checked::new($amount::zero()));
+                *self = lhs / rhs;
+            }
+        }
+
+        impl_checked_amount!(@forward $amount, Add, add, AddAssign, add_assign);
+        impl_checked_amount!(@forward $amount, Sub, sub, SubAssign, sub_assign);
+    };
+    (@forward $amount:ident, $op:ident, $op_fn:ident, $assign:ident, $assign_fn:ident) => {
+        impl<T> $op for Checked<$amount<T>> {
+            type Output = Self;
+
+            fn $op_fn(self, rhs: Self) -> Self::Output {
+                self.zip_with(rhs, |lhs, rhs| Checked::new(lhs).$op_fn(rhs).0)
+            }
+        }
+
+        impl<T> $assign<$amount<T>> for Checked<$amount<T>> {
+            fn $assign_fn(&mut self, rhs: $amount<T>) {
+                let lhs = std::mem::replace(self, Checked::new($amount::zero()));
+                *self = lhs.$op_fn(rhs);
+            }
+        }
+    };
+}
+
+impl_checked_amount!(AmountU64, Uint64);
+impl_checked_amount!(AmountU128, Uint128);
+impl_checked_amount!(AmountU256, Uint256);
+
+impl<T> CheckedCoin<T> {
+    /// Switches to non-panicking arithmetic. See [`Checked`].
+    pub fn checked(self) -> Checked<Self> {
+        Checked::new(self)
+    }
+}
+
+impl<T> Add<AmountU128<T>> for Checked<CheckedCoin<T>> {
+    type Output = Self;
+
+    fn add(self, rhs: AmountU128<T>) -> Self::Output {
+        self.and_then(|lhs| {
+            let amount = lhs
+                .amount
+                .0
+                .checked_add(rhs.0)
+                .map_err(|_| MonetaryError::Overflow {
+                    operation: OverflowOperation::Add,
+                    denom: lhs.denom.to_string(),
+                    lhs: lhs.amount.to_string(),
+                    rhs: rhs.to_string(),
+                })?;
+            Ok(CheckedCoin::new(lhs.denom, AmountU128::new(amount)))
+        })
+    }
+}
+
+impl<T> Sub<AmountU128<T>> for Checked<CheckedCoin<T>> {
+    type Output = Self;
+
+    fn sub(self, rhs: AmountU128<T>) -> Self::Output {
+        self.and_then(|lhs| {
+            let amount = lhs
+                .amount
+                .0
+                .checked_sub(rhs.0)
+                .map_err(|_| MonetaryError::Underflow {
+                    denom: lhs.denom.to_string(),
+                    lhs: lhs.amount.to_string(),
+                    rhs: rhs.to_string(),
+                })?;
+            Ok(CheckedCoin::new(lhs.denom, AmountU128::new(amount)))
+        })
+    }
+}
+
+impl<T> Add<CheckedCoin<T>> for Checked<CheckedCoin<T>> {
+    type Output = Self;
+
+    fn add(self, rhs: CheckedCoin<T>) -> Self::Output {
+        self.and_then(|lhs| ensure_same_denom(lhs, &rhs)) + rhs.amount
+    }
+}
+
+impl<T> Sub<CheckedCoin<T>> for Checked<CheckedCoin<T>> {
+    type Output = Self;
+
+    fn sub(self, rhs: CheckedCoin<T>) -> Self::Output {
+        self.and_then(|lhs| ensure_same_denom(lhs, &rhs)) - rhs.amount
+    }
+}
+
+fn ensure_same_denom<T>(
+    lhs: CheckedCoin<T>,
+    rhs: &CheckedCoin<T>,
+) -> Result<CheckedCoin<T>, MonetaryError> {
+    if lhs.denom.repr() != rhs.denom.repr() {
+        return Err(MonetaryError::DenomMismatch(
+            lhs.denom.to_string(),
+            rhs.denom.to_string(),
+        ));
+    }
+    Ok(lhs)
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::OverflowOperation;
+
+    use crate::test::{amount, Usd};
+    use crate::{Denom, MonetaryError};
+
+    #[test]
+    fn chains_without_panicking() {
+        let res = (amount(10).checked() + amount(5) - amount(3)).result();
+        assert_eq!(res, Ok(amount(12)));
+
+        let err = (amount(10).checked() - amount(11) + amount(5))
+            .result()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            MonetaryError::Underflow {
+                denom: std::any::type_name::<Usd>().to_string(),
+                lhs: "10".to_string(),
+                rhs: "11".to_string(),
+            }
+        );
+
+        let err = (amount(u128::MAX).checked() + amount(1))
+            .result()
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            MonetaryError::Overflow {
+                operation: OverflowOperation::Add,
+                ..
+            }
+        ));
+
+        let mut acc = amount(1).checked();
+        acc += amount(2);
+        acc *= 4u128;
+        assert_eq!(acc, amount(12).checked());
+        acc /= 0u128;
+        assert!(matches!(
+            acc.result(),
+            Err(MonetaryError::DivideByZero { .. })
+        ));
+    }
+
+    #[test]
+    fn coins_report_denom() {
+        let usd = Denom::<Usd>::new("uusd");
+        let err = (usd.coin(amount(1)).checked() - usd.coin(amount(2)))
+            .result()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            MonetaryError::Underflow {
+                denom: "uusd".to_string(),
+                lhs: "1".to_string(),
+                rhs: "2".to_string(),
+            }
+        );
+
+        let other = Denom::<Usd>::new("uusdc");
+        let err = (usd.coin(amount(1)).checked() + other.coin(amount(2)))
+            .result()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            MonetaryError::DenomMismatch("uusd".to_string(), "uusdc".to_string())
+        );
+    }
+}
@@ -0,0 +1,241 @@
+use std::collections::BTreeMap;
+
+use cosmwasm_std::{Addr, BankMsg, Coin, Uint128};
+
+use crate::{AmountU128, CheckedCoin, Denom, MonetaryError};
+
+/// A collection of coins of different denominations, the typed counterpart to `Vec<Coin>`.
+///
+/// Amounts are keyed by denom, so each denomination appears at most once, and conversion to
+/// `Vec<Coin>` is always sorted by denom. Zero amounts are kept until [`CheckedCoins::normalize`]
+/// is called.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Vec<Coin>", into = "Vec<Coin>"))]
+pub struct CheckedCoins(BTreeMap<String, Uint128>);
+
+impl CheckedCoins {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// The denoms held, in sorted order.
+    pub fn denoms(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+
+    /// Returns the amount of `denom` held, or zero if there is none.
+    pub fn get<T>(&self, denom: &Denom<T>) -> AmountU128<T> {
+        self.0
+            .get(denom.repr())
+            .map_or_else(AmountU128::zero, |amount| AmountU128::new(*amount))
+    }
+
+    /// Adds `coin` to the collection. Errors if the total overflows.
+    pub fn add<T>(&mut self, coin: CheckedCoin<T>) -> Result<(), MonetaryError> {
+        let held = self.0.entry(coin.denom.repr().to_string()).or_default();
+        *held = held
+            .checked_add(coin.amount.uint128())
+            .map_err(|err| MonetaryError::Overflow {
+                operation: err.operation,
+                denom: coin.denom.to_string(),
+                lhs: held.to_string(),
+                rhs: coin.amount.to_string(),
+            })?;
+        Ok(())
+    }
+
+    /// Removes `coin` from the collection. Errors, leaving the collection unchanged, if less
+    /// than `coin` is held.
+    pub fn sub_checked<T>(&mut self, coin: CheckedCoin<T>) -> Result<(), MonetaryError> {
+        let held = self.get(&coin.denom);
+        let remaining = held
+            .uint128()
+            .checked_sub(coin.amount.uint128())
+            .map_err(|_| MonetaryError::Underflow {
+                denom: coin.denom.to_string(),
+                lhs: held.to_string(),
+                rhs: coin.amount.to_string(),
+            })?;
+        self.0.insert(coin.denom.into(), remaining);
+        Ok(())
+    }
+
+    pub(crate) fn amount_of(&self, denom: &str) -> Option<Uint128> {
+        self.0.get(denom).copied()
+    }
+
+    /// Drops all denoms with a zero amount.
+    pub fn normalize(&mut self) {
+        self.0.retain(|_, amount| !amount.is_zero());
+    }
+
+    /// Sends every coin held, as [`Denom::send`] does for a single denom.
+    pub fn send(&self, to: &Addr) -> BankMsg {
+        BankMsg::Send {
+            to_address: to.to_string(),
+            amount: self.to_coins(),
+        }
+    }
+
+    pub fn to_coins(&self) -> Vec<Coin> {
+        self.0
+            .iter()
+            .map(|(denom, amount)| Coin::new(*amount, denom))
+            .collect()
+    }
+}
+
+impl TryFrom<Vec<Coin>> for CheckedCoins {
+    type Error = MonetaryError;
+
+    fn try_from(val: Vec<Coin>) -> Result<Self, Self::Error> {
+        Self::try_from(val.as_slice())
+    }
+}
+
+/// Errors if a denom appears more than once.
+impl TryFrom<&[Coin]> for CheckedCoins {
+    type Error = MonetaryError;
+
+    fn try_from(val: &[Coin]) -> Result<Self, Self::Error> {
+        let mut coins = BTreeMap::new();
+        for coin in val {
+            if coins.insert(coin.denom.clone(), coin.amount).is_some() {
+                return Err(MonetaryError::DuplicateDenom {
+                    denom: coin.denom.clone(),
+                });
+            }
+        }
+        Ok(CheckedCoins(coins))
+    }
+}
+
+impl From<CheckedCoins> for Vec<Coin> {
+    fn from(val: CheckedCoins) -> Self {
+        val.to_coins()
+    }
+}
+
+impl<T> From<CheckedCoin<T>> for CheckedCoins {
+    fn from(val: CheckedCoin<T>) -> Self {
+        CheckedCoins(BTreeMap::from([(val.denom.into(), val.amount.uint128())]))
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for CheckedCoins {
+    fn schema_name() -> String {
+        "CheckedCoins".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        Vec::<Coin>::json_schema(gen)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::{coin, Uint128};
+    use monetary_macros::denom;
+
+    use crate::{AmountU128, CheckedCoins, Denom, MonetaryError};
+
+    #[denom]
+    pub struct Atom;
+
+    #[denom]
+    pub struct Osmo;
+
+    fn atom() -> Denom<Atom> {
+        Denom::new("uatom")
+    }
+
+    fn osmo() -> Denom<Osmo> {
+        Denom::new("uosmo")
+    }
+
+    #[test]
+    fn typed_access() {
+        let mut coins = CheckedCoins::new();
+        coins
+            .add(osmo().coin(AmountU128::new(Uint128::new(50))))
+            .unwrap();
+        coins
+            .add(atom().coin(AmountU128::new(Uint128::new(100))))
+            .unwrap();
+        coins
+            .add(atom().coin(AmountU128::new(Uint128::new(20))))
+            .unwrap();
+        assert_eq!(coins.get(&atom()).u128(), 120);
+        assert_eq!(coins.get(&Denom::<Atom>::new("ibc/ATOM")).u128(), 0);
+
+        coins
+            .sub_checked(osmo().coin(AmountU128::new(Uint128::new(50))))
+            .unwrap();
+        let err = coins
+            .sub_checked(atom().coin(AmountU128::new(Uint128::new(121))))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            MonetaryError::Underflow {
+                denom: "uatom".to_string(),
+                lhs: "120".to_string(),
+                rhs: "121".to_string(),
+            }
+        );
+        assert_eq!(coins.get(&atom()).u128(), 120);
+
+        assert_eq!(coins.len(), 2);
+        coins.normalize();
+        assert_eq!(coins.denoms().collect::<Vec<_>>(), vec!["uatom"]);
+
+        coins
+            .add(atom().coin(AmountU128::new(Uint128::MAX)))
+            .unwrap_err();
+    }
+
+    #[test]
+    fn conversions() {
+        let raw = vec![coin(5, "uosmo"), coin(0, "ujuno"), coin(7, "uatom")];
+        let coins = CheckedCoins::try_from(raw).unwrap();
+        assert_eq!(
+            Vec::from(coins),
+            vec![coin(7, "uatom"), coin(0, "ujuno"), coin(5, "uosmo")]
+        );
+
+        let err = CheckedCoins::try_from(vec![coin(5, "uatom"), coin(7, "uatom")]).unwrap_err();
+        assert_eq!(
+            err,
+            MonetaryError::DuplicateDenom {
+                denom: "uatom".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn serialization() {
+        let coins = CheckedCoins::try_from(vec![coin(5, "uosmo"), coin(7, "uatom")]).unwrap();
+        let serialized = serde_json_wasm::to_string(&coins).unwrap();
+        assert_eq!(
+            serialized,
+            r#"[{"denom":"uatom","amount":"7"},{"denom":"uosmo","amount":"5"}]"#
+        );
+
+        let deserialized: CheckedCoins = serde_json_wasm::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, coins);
+
+        serde_json_wasm::from_str::<CheckedCoins>(
+            r#"[{"denom":"uatom","amount":"7"},{"denom":"uatom","amount":"5"}]"#,
+        )
+        .unwrap_err();
+    }
+}
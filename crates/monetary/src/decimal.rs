@@ -0,0 +1,218 @@
+use std::marker::PhantomData;
+
+use cosmwasm_std::{Decimal256, OverflowError, OverflowOperation};
+
+use crate::{denom_name, AmountU128, MonetaryError};
+
+/// A fractional amount of `T`, for balances that need sub-unit precision such as reward
+/// indices, interest accumulators and fee accruals.
+///
+/// Converting back to a whole [`AmountU128`] always requires choosing a rounding direction
+/// explicitly, via [`DecimalAmount::floor`], [`DecimalAmount::ceil`] or [`DecimalAmount::round`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub struct DecimalAmount<T>(
+    pub(crate) Decimal256,
+    #[cfg_attr(feature = "serde", serde(skip))] PhantomData<T>,
+);
+
+impl<T> DecimalAmount<T> {
+    #[inline]
+    pub const fn new(amount: Decimal256) -> Self {
+        DecimalAmount(amount, PhantomData)
+    }
+
+    #[inline]
+    pub const fn decimal256(&self) -> Decimal256 {
+        self.0
+    }
+
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::new(Decimal256::zero())
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// Rounds down to a whole amount.
+    pub fn floor(&self) -> Result<AmountU128<T>, MonetaryError> {
+        Ok(AmountU128::new(self.0.to_uint_floor().try_into()?))
+    }
+
+    /// Rounds up to a whole amount.
+    pub fn ceil(&self) -> Result<AmountU128<T>, MonetaryError> {
+        Ok(AmountU128::new(self.0.to_uint_ceil().try_into()?))
+    }
+
+    /// Rounds to the nearest whole amount, with halves rounded up.
+    pub fn round(&self) -> Result<AmountU128<T>, MonetaryError> {
+        let half = Decimal256::percent(50);
+        let rounded = self
+            .0
+            .checked_add(half)
+            .map_err(|_| MonetaryError::Overflow {
+                operation: OverflowOperation::Add,
+                denom: denom_name::<T>(),
+                lhs: self.to_string(),
+                rhs: half.to_string(),
+            })?;
+        Ok(AmountU128::new(rounded.to_uint_floor().try_into()?))
+    }
+
+    pub fn checked_add(self, other: Self) -> Result<Self, OverflowError> {
+        Ok(Self::new(self.0.checked_add(other.0)?))
+    }
+
+    pub fn checked_sub(self, other: Self) -> Result<Self, OverflowError> {
+        Ok(Self::new(self.0.checked_sub(other.0)?))
+    }
+
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn saturating_add(self, other: Self) -> Self {
+        Self::new(self.0.saturating_add(other.0))
+    }
+
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Self::new(self.0.saturating_sub(other.0))
+    }
+}
+
+impl<T> From<AmountU128<T>> for DecimalAmount<T> {
+    fn from(val: AmountU128<T>) -> Self {
+        Self::new(Decimal256::from_ratio(val.0, 1u128))
+    }
+}
+
+impl<T> std::ops::Add for DecimalAmount<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.0 + rhs.0)
+    }
+}
+
+impl<T> std::ops::Add<AmountU128<T>> for DecimalAmount<T> {
+    type Output = Self;
+
+    fn add(self, rhs: AmountU128<T>) -> Self::Output {
+        self + Self::from(rhs)
+    }
+}
+
+impl<T> std::ops::Sub for DecimalAmount<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.0 - rhs.0)
+    }
+}
+
+impl<T> std::ops::Sub<AmountU128<T>> for DecimalAmount<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: AmountU128<T>) -> Self::Output {
+        self - Self::from(rhs)
+    }
+}
+
+impl<T> std::ops::AddAssign for DecimalAmount<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl<T> std::ops::AddAssign<AmountU128<T>> for DecimalAmount<T> {
+    fn add_assign(&mut self, rhs: AmountU128<T>) {
+        self.0 += Self::from(rhs).0;
+    }
+}
+
+impl<T> std::ops::SubAssign for DecimalAmount<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl<T> std::ops::SubAssign<AmountU128<T>> for DecimalAmount<T> {
+    fn sub_assign(&mut self, rhs: AmountU128<T>) {
+        self.0 -= Self::from(rhs).0;
+    }
+}
+
+impl<T> std::fmt::Display for DecimalAmount<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<T: PartialEq> PartialOrd for DecimalAmount<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.0.cmp(&other.0))
+    }
+}
+
+impl<T: Eq> Ord for DecimalAmount<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<T: Default> Default for DecimalAmount<T> {
+    fn default() -> Self {
+        Self::new(Decimal256::default())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use cosmwasm_std::{Decimal, Decimal256};
+    use monetary_macros::denom;
+
+    use crate::test::{amount, Usd};
+    use crate::{DecimalAmount, Exchange, Rate};
+
+    #[denom]
+    pub struct Btc;
+
+    #[test]
+    fn rounding() {
+        let accrued = DecimalAmount::<Usd>::new(Decimal256::from_str("12.5").unwrap());
+        assert_eq!(accrued.floor().unwrap(), amount(12));
+        assert_eq!(accrued.ceil().unwrap(), amount(13));
+        assert_eq!(accrued.round().unwrap(), amount(13));
+        assert_eq!((accrued + amount(3)).floor().unwrap(), amount(15));
+
+        let huge = DecimalAmount::<Usd>::from(amount(u128::MAX)) + amount(1);
+        huge.floor().unwrap_err();
+    }
+
+    #[test]
+    fn exchange() {
+        let accrued = DecimalAmount::<Usd>::new(Decimal256::from_str("0.001").unwrap());
+        let rate = Rate::<Btc, Usd>::new(Decimal::from_str("0.00002").unwrap()).unwrap();
+        let btc: DecimalAmount<Btc> = accrued.mul_floor(&rate);
+        assert_eq!(
+            btc.decimal256(),
+            Decimal256::from_str("0.00000002").unwrap()
+        );
+    }
+
+    #[test]
+    fn serialization() {
+        let a = DecimalAmount::<Usd>::new(Decimal256::from_str("1.25").unwrap());
+        let serialized = serde_json_wasm::to_string(&a).unwrap();
+        assert_eq!(serialized, r#""1.25""#);
+
+        let b: DecimalAmount<Usd> = serde_json_wasm::from_str(&serialized).unwrap();
+        assert_eq!(a, b);
+    }
+}
@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{ConversionOverflowError, OverflowOperation, StdError};
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -7,8 +7,95 @@ pub enum MonetaryError {
     Std(#[from] StdError),
     #[error("Denomination mismatch: {0} != {1}")]
     DenomMismatch(String, String),
-    #[error("Too many denoms")]
-    TooManyDenoms {},
+    #[error("Too many denoms sent: {}", denoms.join(", "))]
+    TooManyDenoms { denoms: Vec<String> },
     #[error("Denom not found: {0}")]
     DenomNotFound(String),
+    #[error("{operation} overflow: {lhs} and {rhs} ({denom})")]
+    Overflow {
+        operation: OverflowOperation,
+        denom: String,
+        lhs: String,
+        rhs: String,
+    },
+    #[error("Underflow: {lhs} - {rhs} ({denom})")]
+    Underflow {
+        denom: String,
+        lhs: String,
+        rhs: String,
+    },
+    #[error("Divide by zero: {lhs} / 0 ({denom})")]
+    DivideByZero { denom: String, lhs: String },
+    #[error("{0}")]
+    ConversionOverflow(#[from] ConversionOverflowError),
+    #[error("Negative amount: {amount} ({denom})")]
+    NegativeAmount { denom: String, amount: String },
+    #[error("Invalid amount: {0}")]
+    InvalidAmount(String),
+    #[error("Rate of {numerator} per {denominator} rounds to zero")]
+    ZeroRate {
+        numerator: String,
+        denominator: String,
+    },
+    #[error("Invalid spread: bid {bid} is above ask {ask}")]
+    InvalidSpread { bid: String, ask: String },
+    #[error("{0} is above 100%")]
+    AboveOneHundredPercent(String),
+    #[error("Stale rate: observed at {time}, {age}s ago, max age is {max_age}s")]
+    StaleRate {
+        time: String,
+        age: u64,
+        max_age: u64,
+    },
+    #[error("Rate {rate} is outside of bounds [{min}, {max}]")]
+    RateOutOfBounds {
+        rate: String,
+        min: String,
+        max: String,
+    },
+    #[error("Rate {rate} deviates from {reference} by more than {max_deviation}")]
+    ExcessiveDeviation {
+        rate: String,
+        reference: String,
+        max_deviation: String,
+    },
+    #[error("Rate {rate} is below {min}")]
+    RateTooLow { rate: String, min: String },
+    #[error("Rate {rate} is above {max}")]
+    RateTooHigh { rate: String, max: String },
+    #[error("Duplicate denom: {denom}")]
+    DuplicateDenom { denom: String },
+    #[error("No funds sent")]
+    NoFunds {},
+    #[error("Expected {expected}, received {received}")]
+    UnexpectedDenom { expected: String, received: String },
+    #[error("Zero amount of {denom} sent")]
+    ZeroAmount { denom: String },
+    #[error("Unexpected denoms sent: {}", denoms.join(", "))]
+    ExtraDenoms { denoms: Vec<String> },
+    #[error("Insufficient payment: expected {expected}, received {received} ({denom})")]
+    InsufficientPayment {
+        denom: String,
+        expected: String,
+        received: String,
+    },
+    #[error("Excessive payment: expected {expected}, received {received} ({denom})")]
+    ExcessivePayment {
+        denom: String,
+        expected: String,
+        received: String,
+    },
+    #[error("Invalid payment range: max {max} is below min {min} ({denom})")]
+    InvalidPaymentRange {
+        denom: String,
+        min: String,
+        max: String,
+    },
+    #[error("Too many decimal places in {input}: at most {decimals} allowed")]
+    TooManyDecimals { input: String, decimals: u8 },
+}
+
+/// Name used to identify a denomination type parameter in error messages.
+pub(crate) fn denom_name<T>() -> String {
+    std::any::type_name::<T>().to_string()
 }
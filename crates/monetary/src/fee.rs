@@ -0,0 +1,182 @@
+use cosmwasm_std::{Decimal, Uint256};
+
+use crate::{AmountU128, MonetaryError, Rounding};
+
+pub(crate) const BPS_PER_ONE: u16 = 10_000;
+
+/// A fraction in basis points (1/100th of a percent), at most 100%.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u16", into = "u16"))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Bps(u16);
+
+impl Bps {
+    pub const ZERO: Self = Bps(0);
+    pub const MAX: Self = Bps(BPS_PER_ONE);
+
+    /// Returns None if `bps` is above 100%.
+    pub const fn new(bps: u16) -> Option<Self> {
+        if bps > BPS_PER_ONE {
+            None
+        } else {
+            Some(Bps(bps))
+        }
+    }
+
+    pub const fn u16(&self) -> u16 {
+        self.0
+    }
+
+    pub fn decimal(&self) -> Decimal {
+        Decimal::from_ratio(self.0, BPS_PER_ONE)
+    }
+}
+
+/// A whole percentage, at most 100%.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u8", into = "u8"))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Percent(u8);
+
+impl Percent {
+    pub const ZERO: Self = Percent(0);
+    pub const MAX: Self = Percent(100);
+
+    /// Returns None if `percent` is above 100.
+    pub const fn new(percent: u8) -> Option<Self> {
+        if percent > 100 {
+            None
+        } else {
+            Some(Percent(percent))
+        }
+    }
+
+    pub const fn u8(&self) -> u8 {
+        self.0
+    }
+
+    pub fn decimal(&self) -> Decimal {
+        Decimal::percent(self.0 as u64)
+    }
+}
+
+impl From<Percent> for Bps {
+    fn from(val: Percent) -> Self {
+        Bps(val.0 as u16 * 100)
+    }
+}
+
+impl TryFrom<u16> for Bps {
+    type Error = MonetaryError;
+
+    fn try_from(val: u16) -> Result<Self, Self::Error> {
+        Bps::new(val).ok_or_else(|| MonetaryError::AboveOneHundredPercent(format!("{val}bps")))
+    }
+}
+
+impl From<Bps> for u16 {
+    fn from(val: Bps) -> Self {
+        val.0
+    }
+}
+
+impl TryFrom<u8> for Percent {
+    type Error = MonetaryError;
+
+    fn try_from(val: u8) -> Result<Self, Self::Error> {
+        Percent::new(val).ok_or_else(|| MonetaryError::AboveOneHundredPercent(format!("{val}%")))
+    }
+}
+
+impl From<Percent> for u8 {
+    fn from(val: Percent) -> Self {
+        val.0
+    }
+}
+
+impl std::fmt::Display for Bps {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}bps", self.0)
+    }
+}
+
+impl std::fmt::Display for Percent {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}%", self.0)
+    }
+}
+
+impl<T> AmountU128<T> {
+    /// Splits off a fee of `bps`, rounding the fee as specified. Returns `(net, fee)`, which
+    /// always sum to `self`.
+    pub fn fee(self, bps: &Bps, rounding: Rounding) -> (Self, Self) {
+        let fee = rounding.mul_div(
+            self.0.into(),
+            Uint256::from(bps.0),
+            Uint256::from(BPS_PER_ONE),
+        );
+        // bps is at most 100%, so the fee never exceeds self.
+        let fee = Self::new(fee.try_into().unwrap());
+        (Self::new(self.0 - fee.0), fee)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::Decimal;
+
+    use crate::test::amount;
+    use crate::{Bps, MonetaryError, Percent, Rounding};
+
+    #[test]
+    fn validation() {
+        assert_eq!(Bps::new(30).unwrap().decimal(), Decimal::bps(30));
+        assert_eq!(Percent::new(30).unwrap().decimal(), Decimal::percent(30));
+        assert_eq!(Bps::from(Percent::MAX), Bps::MAX);
+        assert_eq!(Bps::new(10_001), None);
+        assert_eq!(Percent::new(101), None);
+        assert_eq!(
+            Bps::try_from(10_001).unwrap_err(),
+            MonetaryError::AboveOneHundredPercent("10001bps".to_string())
+        );
+    }
+
+    #[test]
+    fn fee() {
+        let bps = Bps::new(30).unwrap();
+        assert_eq!(
+            amount(1_000).fee(&bps, Rounding::Floor),
+            (amount(997), amount(3))
+        );
+        assert_eq!(
+            amount(999).fee(&bps, Rounding::Floor),
+            (amount(997), amount(2))
+        );
+        assert_eq!(
+            amount(999).fee(&bps, Rounding::Ceil),
+            (amount(996), amount(3))
+        );
+        assert_eq!(
+            amount(u128::MAX).fee(&Bps::MAX, Rounding::Ceil),
+            (amount(0), amount(u128::MAX))
+        );
+        assert_eq!(
+            amount(7).fee(&Percent::new(50).unwrap().into(), Rounding::HalfEven),
+            (amount(3), amount(4))
+        );
+    }
+
+    #[test]
+    fn serialization() {
+        let bps = Bps::new(30).unwrap();
+        assert_eq!(serde_json_wasm::to_string(&bps).unwrap(), "30");
+        assert_eq!(serde_json_wasm::from_str::<Bps>("30").unwrap(), bps);
+        serde_json_wasm::from_str::<Bps>("10001").unwrap_err();
+
+        let percent = Percent::new(5).unwrap();
+        assert_eq!(serde_json_wasm::to_string(&percent).unwrap(), "5");
+        serde_json_wasm::from_str::<Percent>("101").unwrap_err();
+    }
+}
@@ -0,0 +1,161 @@
+use cosmwasm_std::{OverflowOperation, Uint128};
+
+use crate::{denom_name, AmountU128, MonetaryError, Precise};
+
+impl<T> AmountU128<T> {
+    /// Formats the amount in display units, e.g. `1234567` at 6 decimals is `"1.234567"`.
+    /// Trailing fractional zeros are omitted.
+    pub fn format_precise(&self, precision: &Precise<T>) -> String {
+        self.format_with_decimals(precision.decimals())
+    }
+
+    /// Parses an amount given in display units, e.g. `"1.5"` at 6 decimals is `1500000`.
+    /// Errors if the input has more fractional digits than the precision allows, or with
+    /// [`MonetaryError::Overflow`] if the amount does not fit in 128 bits.
+    pub fn parse_precise(input: &str, precision: &Precise<T>) -> Result<Self, MonetaryError> {
+        Self::parse_with_decimals(input, precision.decimals())
+    }
+
+    pub(crate) fn format_with_decimals(&self, decimals: u8) -> String {
+        let decimals = decimals as usize;
+        let digits = format!("{:0>width$}", self.0, width = decimals + 1);
+        let (whole, fractional) = digits.split_at(digits.len() - decimals);
+        let fractional = fractional.trim_end_matches('0');
+        if fractional.is_empty() {
+            whole.to_string()
+        } else {
+            format!("{whole}.{fractional}")
+        }
+    }
+
+    pub(crate) fn parse_with_decimals(input: &str, decimals: u8) -> Result<Self, MonetaryError> {
+        let invalid = || MonetaryError::InvalidAmount(input.to_string());
+        let (whole, fractional) = match input.split_once('.') {
+            Some((whole, fractional)) if !fractional.is_empty() => (whole, fractional),
+            Some(_) => return Err(invalid()),
+            None => (input, ""),
+        };
+        let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+        if whole.is_empty() || !is_digits(whole) || !is_digits(fractional) {
+            return Err(invalid());
+        }
+        if fractional.len() > decimals as usize {
+            return Err(MonetaryError::TooManyDecimals {
+                input: input.to_string(),
+                decimals,
+            });
+        }
+
+        let digits = format!("{whole}{fractional:0<width$}", width = decimals as usize);
+        let digits = digits.trim_start_matches('0');
+        if digits.is_empty() {
+            return Ok(Self::zero());
+        }
+        // Only digits remain, so the parse can fail solely because the amount is too large.
+        let amount = digits
+            .parse::<u128>()
+            .map_err(|_| MonetaryError::Overflow {
+                operation: OverflowOperation::Mul,
+                denom: denom_name::<T>(),
+                lhs: input.to_string(),
+                rhs: format!("1e{decimals}"),
+            })?;
+        Ok(Self::new(Uint128::new(amount)))
+    }
+}
+
+/// Serde adapters for [`AmountU128`] fields stored in display units rather than base units.
+///
+/// The number of decimals is given as a const generic, e.g.
+/// ```ignore
+/// #[serde(
+///     serialize_with = "monetary::display_units::serialize::<6, _, _>",
+///     deserialize_with = "monetary::display_units::deserialize::<6, _, _>"
+/// )]
+/// min_deposit: AmountU128<Usdc>,
+/// ```
+#[cfg(feature = "serde")]
+pub mod display_units {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    use crate::AmountU128;
+
+    pub fn serialize<const DECIMALS: u8, T, S: Serializer>(
+        amount: &AmountU128<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&amount.format_with_decimals(DECIMALS))
+    }
+
+    pub fn deserialize<'de, const DECIMALS: u8, T, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<AmountU128<T>, D::Error> {
+        let input = String::deserialize(deserializer)?;
+        AmountU128::parse_with_decimals(&input, DECIMALS).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::{amount, Usd};
+    use crate::{AmountU128, MonetaryError, Precise};
+
+    #[test]
+    fn format_precise() {
+        let usd = Precise::new(Usd, 6);
+        assert_eq!(amount(1_234_567).format_precise(&usd), "1.234567");
+        assert_eq!(amount(1_500_000).format_precise(&usd), "1.5");
+        assert_eq!(amount(2_000_000).format_precise(&usd), "2");
+        assert_eq!(amount(5).format_precise(&usd), "0.000005");
+        assert_eq!(amount(0).format_precise(&usd), "0");
+        assert_eq!(amount(42).format_precise(&Precise::new(Usd, 0)), "42");
+    }
+
+    #[test]
+    fn parse_precise() {
+        let usd = Precise::new(Usd, 6);
+        let parse = |input| AmountU128::parse_precise(input, &usd);
+        assert_eq!(parse("1.5").unwrap(), amount(1_500_000));
+        assert_eq!(parse("0.000001").unwrap(), amount(1));
+        assert_eq!(parse("007").unwrap(), amount(7_000_000));
+        assert_eq!(parse("0").unwrap(), amount(0));
+        assert_eq!(
+            parse("1.0000001").unwrap_err(),
+            MonetaryError::TooManyDecimals {
+                input: "1.0000001".to_string(),
+                decimals: 6
+            }
+        );
+        for invalid in ["", ".5", "1.", "-1", "1,5", "1e6"] {
+            assert_eq!(
+                parse(invalid).unwrap_err(),
+                MonetaryError::InvalidAmount(invalid.to_string())
+            );
+        }
+        assert!(matches!(
+            parse("1000000000000000000000000000000000").unwrap_err(),
+            MonetaryError::Overflow { .. }
+        ));
+    }
+
+    #[test]
+    fn display_units_serde() {
+        #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+        struct Config {
+            #[serde(
+                serialize_with = "crate::display_units::serialize::<6, _, _>",
+                deserialize_with = "crate::display_units::deserialize::<6, _, _>"
+            )]
+            min_deposit: AmountU128<Usd>,
+        }
+
+        let config = Config {
+            min_deposit: amount(2_500_000),
+        };
+        let serialized = serde_json_wasm::to_string(&config).unwrap();
+        assert_eq!(serialized, r#"{"min_deposit":"2.5"}"#);
+
+        let deserialized: Config = serde_json_wasm::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, config);
+    }
+}
@@ -0,0 +1,344 @@
+use std::marker::PhantomData;
+
+use cosmwasm_std::{Decimal256, Env, OverflowOperation, Timestamp, Uint256};
+
+use crate::{denom_name, AmountU128, MonetaryError, Rounding};
+
+pub const SECONDS_PER_YEAR: u64 = 365 * 24 * 60 * 60;
+
+/// An interest rate, stored per second and compounded every second.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct InterestRate(Decimal256);
+
+impl InterestRate {
+    pub const fn from_per_second(rate: Decimal256) -> Self {
+        InterestRate(rate)
+    }
+
+    /// Creates a rate from a simple annual rate, e.g. `0.05` for 5% APR. Rounds down.
+    pub fn from_apr(apr: Decimal256) -> Self {
+        InterestRate(Decimal256::new(
+            apr.atomics() / Uint256::from(SECONDS_PER_YEAR),
+        ))
+    }
+
+    /// Creates a rate from an annual yield including compounding, e.g. `0.05` for 5% APY.
+    /// Rounds down to the nearest representable per-second rate.
+    pub fn from_apy(apy: Decimal256) -> Self {
+        let target = Decimal256::one().saturating_add(apy);
+        // (1 + r)^n >= 1 + rn, so the APR rate is an upper bound.
+        let mut lo = Uint256::zero();
+        let mut hi = Self::from_apr(apy).0.atomics();
+        while lo < hi {
+            let mid = hi - (hi - lo) / Uint256::from(2u8);
+            let grown = InterestRate(Decimal256::new(mid)).growth(SECONDS_PER_YEAR);
+            if grown.is_some_and(|grown| grown <= target) {
+                lo = mid;
+            } else {
+                hi = mid - Uint256::one();
+            }
+        }
+        InterestRate(Decimal256::new(lo))
+    }
+
+    pub const fn per_second(&self) -> Decimal256 {
+        self.0
+    }
+
+    /// The simple annual rate.
+    pub fn apr(&self) -> Result<Decimal256, MonetaryError> {
+        self.0
+            .checked_mul(Decimal256::from_ratio(SECONDS_PER_YEAR, 1u64))
+            .map_err(|_| self.overflow(String::new(), SECONDS_PER_YEAR))
+    }
+
+    /// The annual yield including compounding.
+    pub fn apy(&self) -> Result<Decimal256, MonetaryError> {
+        Ok(self.growth_factor(SECONDS_PER_YEAR)? - Decimal256::one())
+    }
+
+    /// The factor a balance grows by over `elapsed` seconds, i.e. `(1 + rate)^elapsed`.
+    pub fn growth_factor(&self, elapsed: u64) -> Result<Decimal256, MonetaryError> {
+        self.growth(elapsed)
+            .ok_or_else(|| self.overflow(String::new(), elapsed))
+    }
+
+    /// Like [`InterestRate::growth_factor`], but names `T` in the error as the currency accruing
+    /// interest.
+    fn growth_factor_of<T>(&self, elapsed: u64) -> Result<Decimal256, MonetaryError> {
+        self.growth(elapsed)
+            .ok_or_else(|| self.overflow(denom_name::<T>(), elapsed))
+    }
+
+    fn growth(&self, elapsed: u64) -> Option<Decimal256> {
+        // Any power of one is one, however much time has passed.
+        if self.0.is_zero() {
+            return Some(Decimal256::one());
+        }
+        let elapsed = u32::try_from(elapsed).ok()?;
+        Decimal256::one()
+            .checked_add(self.0)
+            .ok()?
+            .checked_pow(elapsed)
+            .ok()
+    }
+
+    fn overflow(&self, denom: String, elapsed: u64) -> MonetaryError {
+        MonetaryError::Overflow {
+            operation: OverflowOperation::Pow,
+            denom,
+            lhs: self.0.to_string(),
+            rhs: format!("{elapsed}s"),
+        }
+    }
+}
+
+/// Compounds `principal` at `rate` for `elapsed` seconds, rounding as specified.
+/// Errors if the result overflows.
+pub fn compound<T>(
+    principal: AmountU128<T>,
+    rate: &InterestRate,
+    elapsed: u64,
+    rounding: Rounding,
+) -> Result<AmountU128<T>, MonetaryError> {
+    let factor = rate.growth_factor_of::<T>(elapsed)?;
+    let grown = rounding.mul_div(
+        principal.0.into(),
+        factor.atomics(),
+        Decimal256::one().atomics(),
+    );
+    grown
+        .try_into()
+        .map(AmountU128::new)
+        .map_err(|_| MonetaryError::Overflow {
+            operation: OverflowOperation::Mul,
+            denom: denom_name::<T>(),
+            lhs: principal.to_string(),
+            rhs: factor.to_string(),
+        })
+}
+
+/// The running product of interest accrued on `T`, starting at one.
+///
+/// Balances are stored scaled down by the index at deposit time, so that every balance accrues
+/// interest whenever the index does: `nominal = scaled * index`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = ""))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CumulativeIndex<T> {
+    index: Decimal256,
+    last_updated: Timestamp,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    denom: PhantomData<T>,
+}
+
+impl<T> CumulativeIndex<T> {
+    /// Starts an index at one in the current block.
+    pub fn new(env: &Env) -> Self {
+        CumulativeIndex {
+            index: Decimal256::one(),
+            last_updated: env.block.time,
+            denom: PhantomData,
+        }
+    }
+
+    pub fn index(&self) -> Decimal256 {
+        self.index
+    }
+
+    pub fn last_updated(&self) -> Timestamp {
+        self.last_updated
+    }
+
+    /// Accrues interest at `rate` since the last update. Errors if the index overflows.
+    pub fn accrue(&mut self, rate: &InterestRate, env: &Env) -> Result<(), MonetaryError> {
+        let elapsed = env
+            .block
+            .time
+            .seconds()
+            .saturating_sub(self.last_updated.seconds());
+        let factor = rate.growth_factor_of::<T>(elapsed)?;
+        self.index = self
+            .index
+            .checked_mul(factor)
+            .map_err(|_| MonetaryError::Overflow {
+                operation: OverflowOperation::Mul,
+                denom: denom_name::<T>(),
+                lhs: self.index.to_string(),
+                rhs: factor.to_string(),
+            })?;
+        self.last_updated = self.last_updated.max(env.block.time);
+        Ok(())
+    }
+
+    /// Converts a scaled balance to its nominal value. Errors if the result overflows.
+    pub fn scale_up(
+        &self,
+        scaled: AmountU128<T>,
+        rounding: Rounding,
+    ) -> Result<AmountU128<T>, MonetaryError> {
+        let nominal = rounding.mul_div(
+            scaled.0.into(),
+            self.index.atomics(),
+            Decimal256::one().atomics(),
+        );
+        nominal
+            .try_into()
+            .map(AmountU128::new)
+            .map_err(|_| MonetaryError::Overflow {
+                operation: OverflowOperation::Mul,
+                denom: denom_name::<T>(),
+                lhs: scaled.to_string(),
+                rhs: self.index.to_string(),
+            })
+    }
+
+    /// Converts a nominal balance to its scaled value.
+    pub fn scale_down(
+        &self,
+        nominal: AmountU128<T>,
+        rounding: Rounding,
+    ) -> Result<AmountU128<T>, MonetaryError> {
+        if self.index.is_zero() {
+            return Err(MonetaryError::DivideByZero {
+                denom: denom_name::<T>(),
+                lhs: nominal.to_string(),
+            });
+        }
+        let scaled = rounding.mul_div(
+            nominal.0.into(),
+            Decimal256::one().atomics(),
+            self.index.atomics(),
+        );
+        scaled
+            .try_into()
+            .map(AmountU128::new)
+            .map_err(|_| MonetaryError::Overflow {
+                operation: OverflowOperation::Mul,
+                denom: denom_name::<T>(),
+                lhs: nominal.to_string(),
+                rhs: format!("1 / {}", self.index),
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use cosmwasm_std::{testing::mock_env, Decimal256, OverflowOperation};
+
+    use crate::test::{amount, Usd};
+    use crate::{
+        compound, denom_name, CumulativeIndex, InterestRate, MonetaryError, Rounding,
+        SECONDS_PER_YEAR,
+    };
+
+    #[test]
+    fn conversions() {
+        let apr = Decimal256::percent(5);
+        let rate = InterestRate::from_apr(apr);
+        assert_eq!(rate.per_second(), Decimal256::new(1_585_489_599u128.into()));
+        assert!(rate.apr().unwrap() <= apr);
+
+        // 5% APR compounded every second is ~5.127% APY
+        let apy = rate.apy().unwrap();
+        assert!(apy > Decimal256::from_str("0.05127").unwrap());
+        assert!(apy < Decimal256::from_str("0.05128").unwrap());
+
+        let from_apy = InterestRate::from_apy(Decimal256::percent(5));
+        assert!(from_apy.apy().unwrap() <= Decimal256::percent(5));
+        assert!(from_apy < rate);
+        let next =
+            InterestRate::from_per_second(from_apy.per_second() + Decimal256::new(1u128.into()));
+        assert!(next.apy().unwrap() > Decimal256::percent(5));
+
+        assert_eq!(
+            InterestRate::from_apy(Decimal256::zero()),
+            InterestRate::default()
+        );
+    }
+
+    #[test]
+    fn compounding() {
+        let rate = InterestRate::from_apr(Decimal256::percent(10));
+        let principal = amount(1_000_000);
+        assert_eq!(
+            compound(amount(0), &rate, SECONDS_PER_YEAR, Rounding::Ceil).unwrap(),
+            amount(0)
+        );
+        assert_eq!(
+            compound(amount(1_000_000), &rate, 0, Rounding::Floor).unwrap(),
+            principal
+        );
+
+        let grown = compound(amount(1_000_000), &rate, SECONDS_PER_YEAR, Rounding::Floor).unwrap();
+        assert_eq!(grown, amount(1_105_170));
+        let grown = compound(amount(1_000_000), &rate, SECONDS_PER_YEAR, Rounding::Ceil).unwrap();
+        assert_eq!(grown, amount(1_105_171));
+
+        compound(amount(u128::MAX), &rate, SECONDS_PER_YEAR, Rounding::Floor).unwrap_err();
+        assert_eq!(
+            compound(amount(1), &rate, u64::MAX, Rounding::Floor).unwrap_err(),
+            MonetaryError::Overflow {
+                operation: OverflowOperation::Pow,
+                denom: denom_name::<Usd>(),
+                lhs: rate.per_second().to_string(),
+                rhs: format!("{}s", u64::MAX),
+            }
+        );
+
+        let zero = InterestRate::default();
+        assert_eq!(zero.growth_factor(u64::MAX).unwrap(), Decimal256::one());
+        assert_eq!(
+            compound(amount(1_000), &zero, u64::MAX, Rounding::Ceil).unwrap(),
+            amount(1_000)
+        );
+
+        let max = InterestRate::from_per_second(Decimal256::MAX);
+        assert!(matches!(
+            max.growth_factor(1).unwrap_err(),
+            MonetaryError::Overflow { denom, .. } if denom.is_empty()
+        ));
+        max.apy().unwrap_err();
+    }
+
+    #[test]
+    fn cumulative_index() {
+        let mut env = mock_env();
+        let mut index = CumulativeIndex::<Usd>::new(&env);
+        let rate = InterestRate::from_apr(Decimal256::percent(10));
+
+        let scaled = index
+            .scale_down(amount(1_000_000), Rounding::Floor)
+            .unwrap();
+        assert_eq!(scaled, amount(1_000_000));
+
+        env.block.time = env.block.time.plus_seconds(SECONDS_PER_YEAR);
+        index.accrue(&rate, &env).unwrap();
+        assert_eq!(index.last_updated(), env.block.time);
+        assert_eq!(
+            index.scale_up(scaled, Rounding::Floor).unwrap(),
+            compound(amount(1_000_000), &rate, SECONDS_PER_YEAR, Rounding::Floor).unwrap()
+        );
+
+        // Depositing after accrual gets fewer scaled units
+        let scaled = index
+            .scale_down(amount(1_105_170), Rounding::Floor)
+            .unwrap();
+        assert_eq!(scaled, amount(999_999));
+        assert_eq!(
+            index.scale_up(scaled, Rounding::Ceil).unwrap(),
+            amount(1_105_170)
+        );
+
+        // Accruing twice in one block is a no-op
+        let before = index.index();
+        index.accrue(&rate, &env).unwrap();
+        assert_eq!(index.index(), before);
+    }
+}
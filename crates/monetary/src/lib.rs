@@ -1,16 +1,42 @@
 mod amount;
+mod average;
+mod checked;
 mod coin;
+mod coins;
+mod decimal;
 mod denom;
 mod error;
+mod fee;
+mod format;
+mod interest;
 mod payment;
+mod precision;
 mod rate;
+mod rate256;
+mod rounding;
+mod signed;
+mod spread;
+mod timestamped;
 
 pub use amount::*;
+pub use average::*;
+pub use checked::*;
 pub use coin::*;
+pub use coins::*;
+pub use decimal::*;
 pub use denom::*;
 pub use error::*;
+pub use fee::*;
+pub use format::*;
+pub use interest::*;
 pub use payment::*;
+pub use precision::*;
 pub use rate::*;
+pub use rate256::*;
+pub use rounding::*;
+pub use signed::*;
+pub use spread::*;
+pub use timestamped::*;
 
 pub use monetary_macros::*;
 
@@ -1,36 +1,269 @@
-use cosmwasm_std::{MessageInfo, Uint128};
+use cosmwasm_std::{Addr, BankMsg, MessageInfo, Uint128};
 
-use crate::{AmountU128, CheckedCoin, Denom, MonetaryError};
+use crate::{AmountU128, CheckedCoin, CheckedCoins, Denom, MonetaryError};
 
 /// Requires exactly one denom sent, which matches the requested denom.
 /// Returns the amount if only one denom and non-zero amount. Errors otherwise.
 pub fn must_pay<T>(info: &MessageInfo, denom: &Denom<T>) -> Result<AmountU128<T>, MonetaryError> {
-    if info.funds.len() != 1 {
-        return Err(MonetaryError::DenomNotFound(denom.to_string()));
+    if info.funds.is_empty() {
+        return Err(MonetaryError::NoFunds {});
     }
 
-    let coin = &info.funds[0];
-    if coin.amount.is_zero() || coin.denom != denom.repr() {
-        return Err(MonetaryError::DenomNotFound(denom.to_string()));
+    let amount = may_pay(info, denom)?;
+    if amount.is_zero() {
+        return Err(MonetaryError::ZeroAmount {
+            denom: denom.to_string(),
+        });
     }
 
-    Ok(AmountU128::new(coin.amount))
+    Ok(amount)
 }
 
 /// Similar to must_pay, but it any payment is optional. Returns an error if a different
 /// denom was sent. Otherwise, returns the amount of `denom` sent, or 0 if nothing sent.
 pub fn may_pay<T>(info: &MessageInfo, denom: &Denom<T>) -> Result<AmountU128<T>, MonetaryError> {
-    if info.funds.is_empty() {
-        Ok(AmountU128::new(Uint128::zero()))
-    } else if info.funds.len() == 1 {
-        if info.funds[0].denom == denom.repr() {
-            Ok(AmountU128::new(info.funds[0].amount))
+    let funds = CheckedCoins::try_from(info.funds.as_slice())?;
+    match info.funds.as_slice() {
+        [] => Ok(AmountU128::zero()),
+        [coin] if coin.denom == denom.repr() => Ok(AmountU128::new(coin.amount)),
+        [coin] => Err(MonetaryError::UnexpectedDenom {
+            expected: denom.to_string(),
+            received: coin.denom.clone(),
+        }),
+        _ if funds.amount_of(denom.repr()).is_some() => Err(MonetaryError::ExtraDenoms {
+            denoms: funds
+                .denoms()
+                .filter(|sent| *sent != denom.repr())
+                .map(str::to_string)
+                .collect(),
+        }),
+        _ => Err(MonetaryError::TooManyDenoms {
+            denoms: funds.denoms().map(str::to_string).collect(),
+        }),
+    }
+}
+
+/// Requires exactly `expected` to be paid, and nothing else.
+pub fn must_pay_exact<T>(
+    info: &MessageInfo,
+    expected: &CheckedCoin<T>,
+) -> Result<AmountU128<T>, MonetaryError> {
+    let paid = must_pay_min(info, expected)?;
+    if paid.0 > expected.amount.0 {
+        return Err(MonetaryError::ExcessivePayment {
+            denom: expected.denom.to_string(),
+            expected: expected.amount.to_string(),
+            received: paid.to_string(),
+        });
+    }
+    Ok(paid)
+}
+
+/// Requires at least `min` to be paid, and nothing else. The whole payment is accepted,
+/// so there is nothing to refund. Returns the amount paid.
+pub fn must_pay_at_least<T>(
+    info: &MessageInfo,
+    min: &CheckedCoin<T>,
+) -> Result<AmountU128<T>, MonetaryError> {
+    must_pay_min(info, min)
+}
+
+/// Requires at least `min` to be paid, and nothing else. A payment over `max` still
+/// succeeds, but only `max` of it is accepted. Returns the accepted amount along with the
+/// excess, which add up to the amount paid; the caller is responsible for refunding the
+/// excess. Errors if `min` and `max` are different denoms, or `max` is below `min`.
+pub fn must_pay_range<T>(
+    info: &MessageInfo,
+    min: &CheckedCoin<T>,
+    max: &CheckedCoin<T>,
+) -> Result<(AmountU128<T>, CheckedCoin<T>), MonetaryError> {
+    if min.denom.repr() != max.denom.repr() {
+        return Err(MonetaryError::DenomMismatch(
+            min.denom.to_string(),
+            max.denom.to_string(),
+        ));
+    }
+    if max.amount.0 < min.amount.0 {
+        return Err(MonetaryError::InvalidPaymentRange {
+            denom: min.denom.to_string(),
+            min: min.amount.to_string(),
+            max: max.amount.to_string(),
+        });
+    }
+
+    let paid = must_pay_min(info, min)?;
+    let accepted = paid.0.min(max.amount.0);
+    let excess = min.denom.coin(AmountU128::new(paid.0 - accepted));
+    Ok((AmountU128::new(accepted), excess))
+}
+
+fn must_pay_min<T>(
+    info: &MessageInfo,
+    min: &CheckedCoin<T>,
+) -> Result<AmountU128<T>, MonetaryError> {
+    let paid = must_pay(info, &min.denom)?;
+    if paid.0 < min.amount.0 {
+        return Err(MonetaryError::InsufficientPayment {
+            denom: min.denom.to_string(),
+            expected: min.amount.to_string(),
+            received: paid.to_string(),
+        });
+    }
+    Ok(paid)
+}
+
+/// Tracks the funds sent with a message as parts of them are used, so that whatever is left
+/// can be refunded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Funds {
+    remaining: CheckedCoins,
+}
+
+impl Funds {
+    /// Errors if a denom was sent more than once.
+    pub fn new(info: &MessageInfo) -> Result<Self, MonetaryError> {
+        Ok(Funds {
+            remaining: CheckedCoins::try_from(info.funds.as_slice())?,
+        })
+    }
+
+    /// Returns the amount of `denom` not yet taken.
+    pub fn remaining<T>(&self, denom: &Denom<T>) -> AmountU128<T> {
+        self.remaining.get(denom)
+    }
+
+    /// Uses `amount` of `denom`. Errors if less than `amount` remains.
+    pub fn take<T>(
+        &mut self,
+        denom: &Denom<T>,
+        amount: AmountU128<T>,
+    ) -> Result<CheckedCoin<T>, MonetaryError> {
+        let remaining = self.remaining(denom);
+        self.remaining
+            .sub_checked(denom.coin(AmountU128::new(amount.0)))
+            .map_err(|_| MonetaryError::InsufficientPayment {
+                denom: denom.to_string(),
+                expected: amount.to_string(),
+                received: remaining.to_string(),
+            })?;
+        Ok(denom.coin(amount))
+    }
+
+    /// Returns a message sending everything not taken to `to`, or `None` if everything was
+    /// taken.
+    pub fn into_refund(mut self, to: &Addr) -> Option<BankMsg> {
+        self.remaining.normalize();
+        if self.remaining.is_empty() {
+            None
         } else {
-            Err(MonetaryError::DenomNotFound(denom.to_string()))
+            Some(self.remaining.send(to))
+        }
+    }
+}
+
+/// A fixed set of typed denoms to accept payment in, i.e. a tuple of up to four `&Denom`s of
+/// different types, or an array of `&Denom`s of the same type.
+pub trait DenomSet {
+    /// The amounts paid in each denom, in the same shape as the set.
+    type Amounts;
+
+    fn reprs(&self) -> Vec<&str>;
+    fn amounts(&self, funds: &CheckedCoins) -> Self::Amounts;
+}
+
+macro_rules! impl_denom_set {
+    ($($t:ident $idx:tt),+) => {
+        impl<$($t),+> DenomSet for ($(&Denom<$t>,)+) {
+            type Amounts = ($(AmountU128<$t>,)+);
+
+            fn reprs(&self) -> Vec<&str> {
+                vec![$(self.$idx.repr()),+]
+            }
+
+            fn amounts(&self, funds: &CheckedCoins) -> Self::Amounts {
+                ($(funds.get(self.$idx),)+)
+            }
+        }
+    };
+}
+
+impl_denom_set!(A 0);
+impl_denom_set!(A 0, B 1);
+impl_denom_set!(A 0, B 1, C 2);
+impl_denom_set!(A 0, B 1, C 2, D 3);
+
+impl<T, const N: usize> DenomSet for [&Denom<T>; N] {
+    type Amounts = [AmountU128<T>; N];
+
+    fn reprs(&self) -> Vec<&str> {
+        self.iter().map(|denom| denom.repr()).collect()
+    }
+
+    fn amounts(&self, funds: &CheckedCoins) -> Self::Amounts {
+        self.map(|denom| funds.get(denom))
+    }
+}
+
+/// Requires a non-zero payment in every denom of `denoms`, and nothing else.
+/// Returns the amounts paid, in the same order as `denoms`.
+pub fn must_pay_many<S: DenomSet>(
+    info: &MessageInfo,
+    denoms: S,
+) -> Result<S::Amounts, MonetaryError> {
+    if info.funds.is_empty() {
+        return Err(MonetaryError::NoFunds {});
+    }
+
+    let funds = checked_funds(info, &denoms)?;
+    for denom in denoms.reprs() {
+        match funds.amount_of(denom) {
+            None => return Err(MonetaryError::DenomNotFound(denom.to_string())),
+            Some(amount) if amount.is_zero() => {
+                return Err(MonetaryError::ZeroAmount {
+                    denom: denom.to_string(),
+                })
+            }
+            Some(_) => {}
+        }
+    }
+    Ok(denoms.amounts(&funds))
+}
+
+/// Accepts payment in any subset of `denoms`, and nothing else.
+/// Returns the amounts paid, with zero for denoms that weren't sent.
+pub fn may_pay_many<S: DenomSet>(
+    info: &MessageInfo,
+    denoms: S,
+) -> Result<S::Amounts, MonetaryError> {
+    let funds = checked_funds(info, &denoms)?;
+    Ok(denoms.amounts(&funds))
+}
+
+/// Errors if `denoms` names a denom twice, a denom was sent twice, or a denom outside of
+/// `denoms` was sent.
+fn checked_funds(
+    info: &MessageInfo,
+    denoms: &impl DenomSet,
+) -> Result<CheckedCoins, MonetaryError> {
+    let accepted = denoms.reprs();
+    for (i, denom) in accepted.iter().enumerate() {
+        if accepted[..i].contains(denom) {
+            return Err(MonetaryError::DuplicateDenom {
+                denom: denom.to_string(),
+            });
         }
-    } else {
-        Err(MonetaryError::TooManyDenoms {})
     }
+
+    let funds = CheckedCoins::try_from(info.funds.as_slice())?;
+    let extra: Vec<String> = funds
+        .denoms()
+        .filter(|denom| !accepted.contains(denom))
+        .map(str::to_string)
+        .collect();
+    if !extra.is_empty() {
+        return Err(MonetaryError::ExtraDenoms { denoms: extra });
+    }
+    Ok(funds)
 }
 
 pub fn amount<T>(info: &MessageInfo, denom: &Denom<T>) -> AmountU128<T> {
@@ -50,21 +283,36 @@ mod test {
     use crate::denom;
 
     use super::*;
-    use cosmwasm_std::testing::mock_info;
-    use cosmwasm_std::{coin, coins};
+    use cosmwasm_std::testing::message_info;
+    use cosmwasm_std::{coin, coins, Addr};
 
     const SENDER: &str = "sender";
 
     #[denom]
     pub struct Atom;
 
+    #[denom]
+    pub struct Osmo;
+
     #[test]
     fn may_pay_works() {
         let atom: Denom<Atom> = Denom::new("uatom");
-        let no_payment = mock_info(SENDER, &[]);
-        let atom_payment = mock_info(SENDER, &coins(100, &atom));
-        let eth_payment = mock_info(SENDER, &coins(100, "wei"));
-        let mixed_payment = mock_info(SENDER, &[coin(50, &atom), coin(120, "wei")]);
+        let no_payment = message_info(&Addr::unchecked(SENDER), &[]);
+        let atom_payment = message_info(&Addr::unchecked(SENDER), &coins(100, &atom));
+        let zero_payment = message_info(&Addr::unchecked(SENDER), &coins(0, &atom));
+        let eth_payment = message_info(&Addr::unchecked(SENDER), &coins(100, "wei"));
+        let mixed_payment = message_info(
+            &Addr::unchecked(SENDER),
+            &[coin(50, &atom), coin(120, "wei")],
+        );
+        let other_payment = message_info(
+            &Addr::unchecked(SENDER),
+            &[coin(50, "ujuno"), coin(120, "wei")],
+        );
+        let duplicate_payment = message_info(
+            &Addr::unchecked(SENDER),
+            &[coin(50, &atom), coin(50, &atom)],
+        );
 
         let res = may_pay(&no_payment, &atom).unwrap();
         assert_eq!(res, AmountU128::zero());
@@ -72,35 +320,311 @@ mod test {
         let res = may_pay(&atom_payment, &atom).unwrap();
         assert_eq!(res, AmountU128::new(100u128.into()));
 
+        let res = may_pay(&zero_payment, &atom).unwrap();
+        assert_eq!(res, AmountU128::zero());
+
         let err = may_pay(&eth_payment, &atom).unwrap_err();
-        assert_eq!(err, MonetaryError::DenomNotFound("uatom".to_string()));
+        assert_eq!(
+            err,
+            MonetaryError::UnexpectedDenom {
+                expected: "uatom".to_string(),
+                received: "wei".to_string(),
+            }
+        );
 
         let err = may_pay(&mixed_payment, &atom).unwrap_err();
-        assert_eq!(err, MonetaryError::TooManyDenoms {});
+        assert_eq!(
+            err,
+            MonetaryError::ExtraDenoms {
+                denoms: vec!["wei".to_string()]
+            }
+        );
+
+        let err = may_pay(&other_payment, &atom).unwrap_err();
+        assert_eq!(
+            err,
+            MonetaryError::TooManyDenoms {
+                denoms: vec!["ujuno".to_string(), "wei".to_string()]
+            }
+        );
+
+        let err = may_pay(&duplicate_payment, &atom).unwrap_err();
+        assert_eq!(
+            err,
+            MonetaryError::DuplicateDenom {
+                denom: "uatom".to_string()
+            }
+        );
     }
 
     #[test]
     fn must_pay_works() {
         let atom: Denom<Atom> = Denom::new("uatom");
-        let no_payment = mock_info(SENDER, &[]);
-        let atom_payment = mock_info(SENDER, &coins(100, &atom));
-        let zero_payment = mock_info(SENDER, &coins(0, &atom));
-        let eth_payment = mock_info(SENDER, &coins(100, "wei"));
-        let mixed_payment = mock_info(SENDER, &[coin(50, &atom), coin(120, "wei")]);
+        let no_payment = message_info(&Addr::unchecked(SENDER), &[]);
+        let atom_payment = message_info(&Addr::unchecked(SENDER), &coins(100, &atom));
+        let zero_payment = message_info(&Addr::unchecked(SENDER), &coins(0, &atom));
+        let eth_payment = message_info(&Addr::unchecked(SENDER), &coins(100, "wei"));
+        let mixed_payment = message_info(
+            &Addr::unchecked(SENDER),
+            &[coin(50, &atom), coin(120, "wei")],
+        );
 
         let res = must_pay(&atom_payment, &atom).unwrap();
         assert_eq!(res, AmountU128::new(100u128.into()));
 
         let err = must_pay(&no_payment, &atom).unwrap_err();
-        assert_eq!(err, MonetaryError::DenomNotFound("uatom".to_string()));
+        assert_eq!(err, MonetaryError::NoFunds {});
 
         let err = must_pay(&zero_payment, &atom).unwrap_err();
-        assert_eq!(err, MonetaryError::DenomNotFound("uatom".to_string()));
+        assert_eq!(
+            err,
+            MonetaryError::ZeroAmount {
+                denom: "uatom".to_string()
+            }
+        );
 
         let err = must_pay(&eth_payment, &atom).unwrap_err();
-        assert_eq!(err, MonetaryError::DenomNotFound("uatom".to_string()));
+        assert_eq!(
+            err,
+            MonetaryError::UnexpectedDenom {
+                expected: "uatom".to_string(),
+                received: "wei".to_string(),
+            }
+        );
 
         let err = must_pay(&mixed_payment, &atom).unwrap_err();
-        assert_eq!(err, MonetaryError::DenomNotFound("uatom".to_string()));
+        assert_eq!(
+            err,
+            MonetaryError::ExtraDenoms {
+                denoms: vec!["wei".to_string()]
+            }
+        );
+    }
+
+    #[test]
+    fn bounded_payments_work() {
+        let atom: Denom<Atom> = Denom::new("uatom");
+        let fee = atom.coin(AmountU128::new(100u128.into()));
+        let payment = |amount: u128| message_info(&Addr::unchecked(SENDER), &coins(amount, &atom));
+
+        let res = must_pay_exact(&payment(100), &fee).unwrap();
+        assert_eq!(res, AmountU128::new(100u128.into()));
+
+        let err = must_pay_exact(&payment(99), &fee).unwrap_err();
+        assert_eq!(
+            err,
+            MonetaryError::InsufficientPayment {
+                denom: "uatom".to_string(),
+                expected: "100".to_string(),
+                received: "99".to_string(),
+            }
+        );
+
+        let err = must_pay_exact(&payment(101), &fee).unwrap_err();
+        assert_eq!(
+            err,
+            MonetaryError::ExcessivePayment {
+                denom: "uatom".to_string(),
+                expected: "100".to_string(),
+                received: "101".to_string(),
+            }
+        );
+
+        let paid = must_pay_at_least(&payment(150), &fee).unwrap();
+        assert_eq!(paid, AmountU128::new(150u128.into()));
+        must_pay_at_least(&payment(99), &fee).unwrap_err();
+
+        let max = atom.coin(AmountU128::new(120u128.into()));
+        let (paid, excess) = must_pay_range(&payment(150), &fee, &max).unwrap();
+        assert_eq!(paid, AmountU128::new(120u128.into()));
+        assert_eq!(excess, atom.coin(AmountU128::new(30u128.into())));
+        let (paid, excess) = must_pay_range(&payment(110), &fee, &max).unwrap();
+        assert_eq!(paid, AmountU128::new(110u128.into()));
+        assert!(excess.amount.is_zero());
+        must_pay_range(&payment(99), &fee, &max).unwrap_err();
+
+        let below_min = atom.coin(AmountU128::new(50u128.into()));
+        let err = must_pay_range(&payment(150), &fee, &below_min).unwrap_err();
+        assert_eq!(
+            err,
+            MonetaryError::InvalidPaymentRange {
+                denom: "uatom".to_string(),
+                min: "100".to_string(),
+                max: "50".to_string(),
+            }
+        );
+
+        let ibc_atom: Denom<Atom> = Denom::new("ibc/ATOM");
+        let other_max = ibc_atom.coin(AmountU128::new(120u128.into()));
+        let err = must_pay_range(&payment(150), &fee, &other_max).unwrap_err();
+        assert_eq!(
+            err,
+            MonetaryError::DenomMismatch("uatom".to_string(), "ibc/ATOM".to_string())
+        );
+
+        let err = must_pay_exact(&payment(0), &fee).unwrap_err();
+        assert_eq!(
+            err,
+            MonetaryError::ZeroAmount {
+                denom: "uatom".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn funds_refund_remainder() {
+        let atom: Denom<Atom> = Denom::new("uatom");
+        let osmo: Denom<Osmo> = Denom::new("uosmo");
+        let sender = Addr::unchecked(SENDER);
+        let info = message_info(&sender, &[coin(100, &osmo), coin(50, &atom)]);
+
+        let mut funds = Funds::new(&info).unwrap();
+        let taken = funds.take(&osmo, AmountU128::new(80u128.into())).unwrap();
+        assert_eq!(taken, osmo.coin(AmountU128::new(80u128.into())));
+        funds.take(&atom, AmountU128::new(50u128.into())).unwrap();
+        assert_eq!(funds.remaining(&osmo), AmountU128::new(20u128.into()));
+
+        let err = funds
+            .take(&osmo, AmountU128::new(21u128.into()))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            MonetaryError::InsufficientPayment {
+                denom: "uosmo".to_string(),
+                expected: "21".to_string(),
+                received: "20".to_string(),
+            }
+        );
+        funds
+            .take(&atom, AmountU128::new(1u128.into()))
+            .unwrap_err();
+
+        assert_eq!(
+            funds.clone().into_refund(&sender),
+            Some(BankMsg::Send {
+                to_address: SENDER.to_string(),
+                amount: coins(20, &osmo),
+            })
+        );
+
+        funds.take(&osmo, AmountU128::new(20u128.into())).unwrap();
+        assert_eq!(funds.into_refund(&sender), None);
+
+        let duplicate = message_info(&sender, &[coin(50, &atom), coin(50, &atom)]);
+        Funds::new(&duplicate).unwrap_err();
+    }
+
+    #[test]
+    fn must_pay_many_works() {
+        let atom: Denom<Atom> = Denom::new("uatom");
+        let osmo: Denom<Osmo> = Denom::new("uosmo");
+        let both = message_info(
+            &Addr::unchecked(SENDER),
+            &[coin(120, &osmo), coin(50, &atom)],
+        );
+
+        let (a, o) = must_pay_many(&both, (&atom, &osmo)).unwrap();
+        assert_eq!(a, AmountU128::new(50u128.into()));
+        assert_eq!(o, AmountU128::new(120u128.into()));
+
+        let no_payment = message_info(&Addr::unchecked(SENDER), &[]);
+        let err = must_pay_many(&no_payment, (&atom, &osmo)).unwrap_err();
+        assert_eq!(err, MonetaryError::NoFunds {});
+
+        let only_atom = message_info(&Addr::unchecked(SENDER), &coins(50, &atom));
+        let err = must_pay_many(&only_atom, (&atom, &osmo)).unwrap_err();
+        assert_eq!(err, MonetaryError::DenomNotFound("uosmo".to_string()));
+
+        let zero_osmo = message_info(&Addr::unchecked(SENDER), &[coin(50, &atom), coin(0, &osmo)]);
+        let err = must_pay_many(&zero_osmo, (&atom, &osmo)).unwrap_err();
+        assert_eq!(
+            err,
+            MonetaryError::ZeroAmount {
+                denom: "uosmo".to_string()
+            }
+        );
+
+        let extra = message_info(
+            &Addr::unchecked(SENDER),
+            &[
+                coin(50, &atom),
+                coin(1, "wei"),
+                coin(120, &osmo),
+                coin(1, "ujuno"),
+            ],
+        );
+        let err = must_pay_many(&extra, (&atom, &osmo)).unwrap_err();
+        assert_eq!(
+            err,
+            MonetaryError::ExtraDenoms {
+                denoms: vec!["ujuno".to_string(), "wei".to_string()]
+            }
+        );
+
+        let duplicate = message_info(
+            &Addr::unchecked(SENDER),
+            &[coin(50, &atom), coin(50, &atom)],
+        );
+        let err = must_pay_many(&duplicate, (&atom,)).unwrap_err();
+        assert_eq!(
+            err,
+            MonetaryError::DuplicateDenom {
+                denom: "uatom".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn denom_sets_reject_duplicates() {
+        let atom: Denom<Atom> = Denom::new("uatom");
+        let aliased: Denom<Osmo> = Denom::new("uatom");
+        let payment = message_info(&Addr::unchecked(SENDER), &coins(100, &atom));
+
+        let err = must_pay_many(&payment, (&atom, &aliased)).unwrap_err();
+        assert_eq!(
+            err,
+            MonetaryError::DuplicateDenom {
+                denom: "uatom".to_string()
+            }
+        );
+
+        let err = may_pay_many(&payment, [&atom, &atom]).unwrap_err();
+        assert_eq!(
+            err,
+            MonetaryError::DuplicateDenom {
+                denom: "uatom".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn may_pay_many_works() {
+        let atom: Denom<Atom> = Denom::new("uatom");
+        let osmo: Denom<Osmo> = Denom::new("uosmo");
+        let only_osmo = message_info(&Addr::unchecked(SENDER), &coins(120, &osmo));
+
+        let (a, o) = may_pay_many(&only_osmo, (&atom, &osmo)).unwrap();
+        assert_eq!(a, AmountU128::zero());
+        assert_eq!(o, AmountU128::new(120u128.into()));
+
+        let no_payment = message_info(&Addr::unchecked(SENDER), &[]);
+        let (a, o) = may_pay_many(&no_payment, (&atom, &osmo)).unwrap();
+        assert!(a.is_zero() && o.is_zero());
+
+        let err = may_pay_many(&only_osmo, (&atom,)).unwrap_err();
+        assert_eq!(
+            err,
+            MonetaryError::ExtraDenoms {
+                denoms: vec!["uosmo".to_string()]
+            }
+        );
+
+        let ibc_atom: Denom<Atom> = Denom::new("ibc/ATOM");
+        let both = message_info(
+            &Addr::unchecked(SENDER),
+            &[coin(5, &ibc_atom), coin(7, &atom)],
+        );
+        let [native, ibc] = may_pay_many(&both, [&atom, &ibc_atom]).unwrap();
+        assert_eq!(native + ibc, AmountU128::new(12u128.into()));
     }
 }
@@ -0,0 +1,237 @@
+use cosmwasm_std::{Decimal, OverflowOperation, Uint128, Uint512};
+
+use crate::{
+    denom_name, scale_rate, AmountU128, Denomination, MonetaryError, Precise, Rate, Rounding,
+};
+
+/// A denomination whose number of decimals is known at compile time.
+pub trait Precision {
+    const DECIMALS: u8;
+}
+
+/// A currency `T` with `D` decimal places, checked by the compiler.
+///
+/// This is the compile-time counterpart of [`Precise`]: `AmountU128<PreciseN<Usdc, 6>>` and
+/// `AmountU128<PreciseN<Usdc, 18>>` are distinct types, and moving between them requires an
+/// explicit rescale such as [`AmountU128::rescale_floor`].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct PreciseN<T, const D: u8>(T);
+
+unsafe impl<T: Denomination, const D: u8> Denomination for PreciseN<T, D> {}
+
+impl<T, const D: u8> Precision for PreciseN<T, D> {
+    const DECIMALS: u8 = D;
+}
+
+impl<T, const D: u8> PreciseN<T, D> {
+    pub const fn new(denom: T) -> Self {
+        PreciseN(denom)
+    }
+
+    pub const fn decimals(&self) -> u8 {
+        D
+    }
+
+    pub fn unwrap(self) -> T {
+        self.0
+    }
+}
+
+impl<T, const D: u8> From<PreciseN<T, D>> for Precise<T> {
+    fn from(val: PreciseN<T, D>) -> Self {
+        Precise::new(val.0, D)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl<T: schemars::JsonSchema, const D: u8> schemars::JsonSchema for PreciseN<T, D> {
+    fn schema_name() -> String {
+        format!("PreciseN_{}_{}", T::schema_name(), D)
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        T::json_schema(gen)
+    }
+}
+
+impl<T, const D: u8> AmountU128<PreciseN<T, D>> {
+    /// Converts to `E` decimals, rounding down when `E < D`.
+    /// Errors if the result overflows when `E > D`.
+    pub fn rescale_floor<const E: u8>(self) -> Result<AmountU128<PreciseN<T, E>>, MonetaryError> {
+        let (scaled, _) = rescale::<T>(self.0, D, E, Rounding::Floor)?;
+        Ok(AmountU128::new(scaled))
+    }
+
+    /// Converts to `E` decimals, rounding up when `E < D`.
+    /// Errors if the result overflows when `E > D`.
+    pub fn rescale_ceil<const E: u8>(self) -> Result<AmountU128<PreciseN<T, E>>, MonetaryError> {
+        let (scaled, _) = rescale::<T>(self.0, D, E, Rounding::Ceil)?;
+        Ok(AmountU128::new(scaled))
+    }
+}
+
+impl<T> AmountU128<T> {
+    /// Converts an amount from one precision of `T` to another, e.g. when bridging a 6 decimal
+    /// token to its 18 decimal representation. Errors if upscaling overflows.
+    pub fn rescale(
+        self,
+        from: &Precise<T>,
+        to: &Precise<T>,
+        rounding: Rounding,
+    ) -> Result<Self, MonetaryError> {
+        let (scaled, _) = self.rescale_with_dust(from, to, rounding)?;
+        Ok(scaled)
+    }
+
+    /// Like [`AmountU128::rescale`], but also returns the dust: the part of `self`, still at the
+    /// `from` precision, that is too small to be represented at the `to` precision. When
+    /// rounding down this is exactly the amount lost, so it can be credited elsewhere.
+    pub fn rescale_with_dust(
+        self,
+        from: &Precise<T>,
+        to: &Precise<T>,
+        rounding: Rounding,
+    ) -> Result<(Self, Self), MonetaryError> {
+        let (scaled, dust) = rescale::<T>(self.0, from.decimals(), to.decimals(), rounding)?;
+        Ok((Self::new(scaled), Self::new(dust)))
+    }
+}
+
+impl<A, B, const DA: u8, const DB: u8> Rate<PreciseN<A, DA>, PreciseN<B, DB>> {
+    /// Creates a rate from a rate in display units, like [`Rate::new_precise`] but with the
+    /// precisions taken from the types.
+    pub fn new_precise_n(rate: Decimal) -> Option<Self> {
+        Self::new(scale_rate(rate, DA, DB)?)
+    }
+}
+
+/// Moves `amount` from `from` to `to` decimal places. Also returns the dust: the remainder,
+/// in `from` units, that is smaller than one unit at the target precision.
+pub(crate) fn rescale<T>(
+    amount: Uint128,
+    from: u8,
+    to: u8,
+    rounding: Rounding,
+) -> Result<(Uint128, Uint128), MonetaryError> {
+    let delta = from.abs_diff(to) as u32;
+    let factor = Uint512::from(10u128).checked_pow(delta);
+    if to >= from {
+        let scaled = factor
+            .ok()
+            .and_then(|factor| Uint512::from(amount).checked_mul(factor).ok())
+            .and_then(|scaled| Uint128::try_from(scaled).ok())
+            .ok_or_else(|| MonetaryError::Overflow {
+                operation: OverflowOperation::Mul,
+                denom: denom_name::<T>(),
+                lhs: amount.to_string(),
+                rhs: format!("1e{delta}"),
+            })?;
+        return Ok((scaled, Uint128::zero()));
+    }
+
+    match factor {
+        Ok(factor) => {
+            let scaled = rounding.div(amount.into(), factor);
+            let dust = Uint512::from(amount) % factor;
+            Ok((scaled.try_into()?, dust.try_into()?))
+        }
+        // A factor that doesn't fit in 512 bits exceeds any amount, so all of it is dust.
+        Err(_) => Ok((
+            rounding.div(amount.into(), Uint512::MAX).try_into()?,
+            amount,
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::{Decimal, Uint128};
+    use monetary_macros::denom;
+
+    use crate::{AmountU128, Precise, PreciseN, Precision, Rate, Rounding};
+
+    #[denom]
+    pub struct Usdc;
+
+    #[denom]
+    pub struct Eth;
+
+    type Usdc6 = PreciseN<Usdc, 6>;
+    type Usdc18 = PreciseN<Usdc, 18>;
+
+    #[test]
+    fn typed_rescale() {
+        let a = AmountU128::<Usdc6>::new(Uint128::new(1_500_000));
+        let b: AmountU128<Usdc18> = a.rescale_floor().unwrap();
+        assert_eq!(b.u128(), 1_500_000_000_000_000_000);
+
+        let dusty = AmountU128::<Usdc18>::new(Uint128::new(1_500_000_000_000_000_001));
+        assert_eq!(dusty.rescale_floor::<6>().unwrap(), a);
+        assert_eq!(dusty.rescale_ceil::<6>().unwrap().u128(), 1_500_001);
+
+        let huge = AmountU128::<Usdc6>::new(Uint128::MAX);
+        huge.rescale_floor::<18>().unwrap_err();
+    }
+
+    #[test]
+    fn runtime_rescale() {
+        let six = Precise::new(Usdc, 6);
+        let eighteen = Precise::new(Usdc, 18);
+        let amount = AmountU128::<Usdc>::new(Uint128::new(1_234_567_890_123_456_789));
+
+        let (scaled, dust) = amount
+            .rescale_with_dust(&eighteen, &six, Rounding::Floor)
+            .unwrap();
+        assert_eq!(scaled.u128(), 1_234_567);
+        assert_eq!(dust.u128(), 890_123_456_789);
+
+        let scaled = amount.rescale(&eighteen, &six, Rounding::Ceil).unwrap();
+        assert_eq!(scaled.u128(), 1_234_568);
+
+        let back = scaled.rescale(&six, &eighteen, Rounding::Floor).unwrap();
+        assert_eq!(back.u128(), 1_234_568_000_000_000_000);
+
+        amount
+            .rescale(&eighteen, &Precise::new(Usdc, 200), Rounding::Floor)
+            .unwrap_err();
+        AmountU128::<Usdc>::new(Uint128::MAX)
+            .rescale(&six, &eighteen, Rounding::Floor)
+            .unwrap_err();
+
+        let (scaled, dust) = amount
+            .rescale_with_dust(&Precise::new(Usdc, 200), &six, Rounding::Ceil)
+            .unwrap();
+        assert_eq!(scaled.u128(), 1);
+        assert_eq!(dust, amount);
+    }
+
+    #[test]
+    fn typed_rate() {
+        // 1 ETH (18 decimals) = 2000 USDC (6 decimals)
+        let rate = Rate::<PreciseN<Usdc, 6>, PreciseN<Eth, 18>>::new_precise_n(
+            Decimal::from_ratio(2000u128, 1u128),
+        )
+        .unwrap();
+        let runtime = Rate::new_precise(
+            Decimal::from_ratio(2000u128, 1u128),
+            &Precise::new(Usdc, 6),
+            &Precise::new(Eth, 18),
+        )
+        .unwrap();
+        assert_eq!(rate.rate(), runtime.rate());
+        assert!(
+            Rate::<PreciseN<Usdc, 18>, PreciseN<Eth, 0>>::new_precise_n(Decimal::from_ratio(
+                10u128.pow(10),
+                1u128
+            ))
+            .is_none()
+        );
+        assert_eq!(<PreciseN<Eth, 18> as Precision>::DECIMALS, 18);
+        assert_eq!(
+            Precise::from(PreciseN::<Eth, 18>::new(Eth)),
+            Precise::new(Eth, 18)
+        );
+    }
+}
@@ -4,15 +4,47 @@ use std::{
     ops::{Div, Mul},
 };
 
-use cosmwasm_std::{Decimal, Fraction, StdResult, Uint128};
+use cosmwasm_std::{
+    CheckedFromRatioError, Decimal, Decimal256, Fraction, OverflowOperation, StdError, Uint128,
+};
 
-use crate::{AmountU128, Precise};
+use crate::{
+    denom_name, AmountU128, AmountU256, Bps, CheckedCoin, DecimalAmount, MonetaryError, Precise,
+    Rounding, BPS_PER_ONE,
+};
 
 #[derive(Clone, Debug, PartialEq, Eq, Copy)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(transparent, bound = ""))]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 /// A rate representing A / B.
+///
+/// Rates are ordered by value, but only against rates of the same `A / B`:
+/// ```
+/// # use cosmwasm_std::Decimal;
+/// # use monetary::Rate;
+/// # #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// # pub struct Usd;
+/// # #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// # pub struct Atom;
+/// let limit = Rate::<Usd, Atom>::new(Decimal::percent(1000)).unwrap();
+/// let market = Rate::<Usd, Atom>::new(Decimal::percent(1020)).unwrap();
+/// assert!(limit < market);
+/// assert_eq!(market.clamp(limit, limit), limit);
+/// ```
+///
+/// Comparing a rate with its inverse, or any other pair, doesn't compile:
+/// ```compile_fail
+/// # use cosmwasm_std::Decimal;
+/// # use monetary::Rate;
+/// # #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// # pub struct Usd;
+/// # #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// # pub struct Atom;
+/// let usd_per_atom = Rate::<Usd, Atom>::new(Decimal::percent(1000)).unwrap();
+/// let atom_per_usd = Rate::<Atom, Usd>::new(Decimal::percent(10)).unwrap();
+/// assert!(usd_per_atom > atom_per_usd);
+/// ```
 pub struct Rate<A, B>(
     Decimal,
     #[cfg_attr(feature = "serde", serde(skip))] PhantomData<(A, B)>,
@@ -42,88 +74,452 @@ impl<A, B> Rate<A, B> {
         self.0
     }
 
-    pub fn inv(&self) -> Rate<B, A> {
-        Rate(self.0.inv().unwrap(), PhantomData)
+    /// Returns the inverse rate, B / A.
+    /// Errors if the inverse is too small to be represented, i.e. rounds to zero.
+    pub fn inv(&self) -> Result<Rate<B, A>, MonetaryError> {
+        self.0
+            .inv()
+            .and_then(Rate::new)
+            .ok_or_else(zero_rate::<B, A>)
     }
 
     /// Adds a Decimal value to this. This is useful for adding a directly calculated
-    /// decimal to a typed rate.
-    pub fn add_decimal(&self, decimal: Decimal) -> StdResult<Rate<A, B>> {
-        Ok(Rate(self.0.checked_add(decimal)?, PhantomData))
+    /// decimal to a typed rate. Errors if the sum overflows.
+    pub fn add_decimal(&self, decimal: Decimal) -> Result<Rate<A, B>, MonetaryError> {
+        let sum = self.0.checked_add(decimal).map_err(StdError::from)?;
+        Rate::new(sum).ok_or_else(zero_rate::<A, B>)
     }
 
     /// Subtracts a Decimal value from this. This is useful for subtracting a directly calculated
-    /// decimal from a typed rate.
-    pub fn sub_decimal(&self, decimal: Decimal) -> StdResult<Rate<A, B>> {
-        Ok(Rate(self.0.checked_sub(decimal)?, PhantomData))
+    /// decimal from a typed rate. Errors if the difference is negative or zero.
+    pub fn sub_decimal(&self, decimal: Decimal) -> Result<Rate<A, B>, MonetaryError> {
+        let difference = self.0.checked_sub(decimal).map_err(StdError::from)?;
+        Rate::new(difference).ok_or_else(zero_rate::<A, B>)
+    }
+}
+
+impl<A, B> Rate<A, B> {
+    /// Creates the rate `numerator / denominator`, e.g. the price of a pool's reserves.
+    /// Errors if either amount is zero, or the ratio is out of range for a [`Decimal`].
+    pub fn from_amounts(
+        numerator: AmountU128<A>,
+        denominator: AmountU128<B>,
+    ) -> Result<Self, MonetaryError> {
+        let ratio =
+            Decimal::checked_from_ratio(numerator.0, denominator.0).map_err(|err| match err {
+                CheckedFromRatioError::DivideByZero => MonetaryError::DivideByZero {
+                    denom: denom_name::<A>(),
+                    lhs: numerator.to_string(),
+                },
+                CheckedFromRatioError::Overflow => MonetaryError::Overflow {
+                    operation: OverflowOperation::Mul,
+                    denom: denom_name::<A>(),
+                    lhs: numerator.to_string(),
+                    rhs: format!("1 / {denominator}"),
+                },
+            })?;
+        Rate::new(ratio).ok_or_else(zero_rate::<A, B>)
+    }
+
+    /// Creates the rate between two coins' amounts, as [`Rate::from_amounts`].
+    pub fn from_coins(
+        numerator: &CheckedCoin<A>,
+        denominator: &CheckedCoin<B>,
+    ) -> Result<Self, MonetaryError> {
+        Self::from_amounts(
+            AmountU128::new(numerator.amount.0),
+            AmountU128::new(denominator.amount.0),
+        )
+    }
+
+    /// Errors if this rate is below `min`.
+    pub fn ensure_at_least(&self, min: &Rate<A, B>) -> Result<(), MonetaryError> {
+        if self.0 < min.0 {
+            return Err(MonetaryError::RateTooLow {
+                rate: self.to_string(),
+                min: min.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Errors if this rate is above `max`.
+    pub fn ensure_at_most(&self, max: &Rate<A, B>) -> Result<(), MonetaryError> {
+        if self.0 > max.0 {
+            return Err(MonetaryError::RateTooHigh {
+                rate: self.to_string(),
+                max: max.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns the relative deviation of this rate from `reference`, i.e.
+    /// `|self - reference| / reference`. Saturates at [`Decimal::MAX`].
+    pub fn deviation_from(&self, reference: &Rate<A, B>) -> Decimal {
+        let diff = self.0.atomics().abs_diff(reference.0.atomics());
+        Decimal::checked_from_ratio(diff, reference.0.atomics()).unwrap_or(Decimal::MAX)
+    }
+
+    /// Errors if this rate deviates from `reference` by more than `max_deviation`.
+    pub fn ensure_within_bps(
+        &self,
+        reference: &Rate<A, B>,
+        max_deviation: Bps,
+    ) -> Result<(), MonetaryError> {
+        if self.deviation_from(reference) > max_deviation.decimal() {
+            return Err(MonetaryError::ExcessiveDeviation {
+                rate: self.to_string(),
+                reference: reference.to_string(),
+                max_deviation: max_deviation.to_string(),
+            });
+        }
+        Ok(())
     }
 }
 
 impl<A, B> Rate<Precise<A>, Precise<B>> {
+    /// Creates a rate between base units from a rate between display units.
+    /// Returns None if the scaled rate is zero or overflows.
     pub fn new_precise(rate: Decimal, from: &Precise<A>, to: &Precise<B>) -> Option<Self> {
-        let a_dec = from.decimals();
-        let b_dec = to.decimals();
-        let delta = a_dec.abs_diff(b_dec) as u32;
-        let rate = match a_dec.cmp(&b_dec) {
-            Ordering::Equal => rate,
-            // If greater, we need to multiply by 10^delta
-            Ordering::Greater => Decimal::from_ratio(
-                rate.numerator() * Uint128::from(10u128.pow(delta)),
-                rate.denominator(),
-            ),
-            // If lesser, we need to divide by 10^delta
-            Ordering::Less => Decimal::from_ratio(
-                rate.numerator(),
-                rate.denominator() * Uint128::from(10u128.pow(delta)),
-            ),
-        };
+        Self::new(scale_rate(rate, from.decimals(), to.decimals())?)
+    }
+}
+
+/// Converts a rate between display units into a rate between base units.
+/// Returns None if the result overflows.
+pub(crate) fn scale_rate(rate: Decimal, a_dec: u8, b_dec: u8) -> Option<Decimal> {
+    let factor = Uint128::from(10u8)
+        .checked_pow(a_dec.abs_diff(b_dec) as u32)
+        .ok()?;
+    let atomics = match a_dec.cmp(&b_dec) {
+        Ordering::Equal => rate.atomics(),
+        // If greater, we need to multiply by 10^delta
+        Ordering::Greater => rate.atomics().checked_mul(factor).ok()?,
+        // If lesser, we need to divide by 10^delta
+        Ordering::Less => rate.atomics().checked_div(factor).ok()?,
+    };
+    Some(Decimal::new(atomics))
+}
+
+impl<N, S> Rate<N, S> {
+    /// Composes `N / S * S / D = N / D`.
+    /// Errors if the product overflows or is too small to be represented.
+    pub fn checked_mul<D>(self, rhs: Rate<S, D>) -> Result<Rate<N, D>, MonetaryError> {
+        let product = self
+            .0
+            .checked_mul(rhs.0)
+            .map_err(|_| MonetaryError::Overflow {
+                operation: OverflowOperation::Mul,
+                denom: String::new(),
+                lhs: self.to_string(),
+                rhs: rhs.to_string(),
+            })?;
+        Rate::new(product).ok_or_else(zero_rate::<N, D>)
+    }
+
+    /// Composes `(N / S) / (D / S) = N / D`.
+    /// Errors if the quotient overflows or is too small to be represented.
+    pub fn checked_div<D>(self, rhs: Rate<D, S>) -> Result<Rate<N, D>, MonetaryError> {
+        let quotient = self.0.checked_div(rhs.0).map_err(|err| match err {
+            CheckedFromRatioError::DivideByZero => MonetaryError::DivideByZero {
+                denom: String::new(),
+                lhs: self.to_string(),
+            },
+            CheckedFromRatioError::Overflow => MonetaryError::Overflow {
+                operation: OverflowOperation::Mul,
+                denom: String::new(),
+                lhs: self.to_string(),
+                rhs: format!("1 / {rhs}"),
+            },
+        })?;
+        Rate::new(quotient).ok_or_else(zero_rate::<N, D>)
+    }
+}
 
-        Self::new(rate)
+pub(crate) fn zero_rate<N, D>() -> MonetaryError {
+    MonetaryError::ZeroRate {
+        numerator: denom_name::<N>(),
+        denominator: denom_name::<D>(),
     }
 }
 
+/// # Panics
+/// Panics if the product overflows or rounds to zero. Use [`Rate::checked_mul`] to handle
+/// these cases.
 impl<N, S, D> Mul<Rate<S, D>> for Rate<N, S> {
     type Output = Rate<N, D>;
 
     fn mul(self, rhs: Rate<S, D>) -> Self::Output {
         // N / S * S / D = N / D
-        // SAFETY: The product of two non-zero decimals is non-zero
-        unsafe { Self::Output::new_unchecked(self.0 * rhs.0) }
+        self.checked_mul(rhs).unwrap_or_else(|err| panic!("{err}"))
     }
 }
 
+/// # Panics
+/// Panics if the quotient overflows or rounds to zero. Use [`Rate::checked_div`] to handle
+/// these cases.
 impl<N, S, D> Div<Rate<D, S>> for Rate<N, S> {
     type Output = Rate<N, D>;
 
     fn div(self, rhs: Rate<D, S>) -> Self::Output {
         // (N / S) / (D / S) = N / D
-        // SAFETY: The division of two non-zero decimals is non-zero
-        unsafe { Self::Output::new_unchecked(self.0 / rhs.0) }
+        self.checked_div(rhs).unwrap_or_else(|err| panic!("{err}"))
     }
 }
 
+/// A typed rate of `N / D` that amounts can be exchanged at, i.e. [`Rate`] or [`Rate256`].
+pub trait ExchangeRate<N, D>: std::fmt::Display {
+    /// The rate as a [`Decimal256`], which every exchange rate fits in losslessly.
+    fn decimal256(&self) -> Decimal256;
+}
+
+impl<N, D> ExchangeRate<N, D> for Rate<N, D> {
+    fn decimal256(&self) -> Decimal256 {
+        self.0.into()
+    }
+}
+
+/// Conversion of an amount of `A` into the equivalent amount of `B`, given a typed rate.
 pub trait Exchange<A, B> {
-    fn mul_floor(&self, rate: &Rate<B, A>) -> AmountU128<B>;
-    fn mul_ceil(&self, rate: &Rate<B, A>) -> AmountU128<B>;
-    fn div_floor(&self, rate: &Rate<A, B>) -> AmountU128<B>;
-    fn div_ceil(&self, rate: &Rate<A, B>) -> AmountU128<B>;
+    type Output;
+
+    /// Converts at `rate`, rounding down.
+    ///
+    /// # Panics
+    /// Panics if the result overflows. Use [`Exchange::exchange`] to handle this case.
+    fn mul_floor<R: ExchangeRate<B, A>>(&self, rate: &R) -> Self::Output;
+    /// Converts at `rate`, rounding up.
+    ///
+    /// # Panics
+    /// Panics if the result overflows. Use [`Exchange::exchange`] to handle this case.
+    fn mul_ceil<R: ExchangeRate<B, A>>(&self, rate: &R) -> Self::Output;
+    /// Converts by dividing by the inverse rate, rounding down. Errors if the result overflows.
+    fn div_floor<R: ExchangeRate<A, B>>(&self, rate: &R) -> Result<Self::Output, MonetaryError>;
+    /// Converts by dividing by the inverse rate, rounding up. Errors if the result overflows.
+    fn div_ceil<R: ExchangeRate<A, B>>(&self, rate: &R) -> Result<Self::Output, MonetaryError>;
+
+    /// Converts at `rate`, rounding as specified. Errors if the result overflows.
+    fn exchange<R: ExchangeRate<B, A>>(
+        &self,
+        rate: &R,
+        rounding: Rounding,
+    ) -> Result<Self::Output, MonetaryError>;
+
+    /// The least output to accept when converting at `rate` with at most `max_slippage`,
+    /// rounded down. Errors if the conversion overflows.
+    fn min_out<R: ExchangeRate<B, A>>(
+        &self,
+        rate: &R,
+        max_slippage: Bps,
+    ) -> Result<Self::Output, MonetaryError>;
+}
+
+macro_rules! impl_exchange_amount {
+    ($amount:ident) => {
+        impl<A, B> Exchange<A, B> for $amount<A> {
+            type Output = $amount<B>;
+
+            fn mul_floor<R: ExchangeRate<B, A>>(&self, rate: &R) -> $amount<B> {
+                self.exchange(rate, Rounding::Floor)
+                    .unwrap_or_else(|err| panic!("{err}"))
+            }
+
+            fn mul_ceil<R: ExchangeRate<B, A>>(&self, rate: &R) -> $amount<B> {
+                self.exchange(rate, Rounding::Ceil)
+                    .unwrap_or_else(|err| panic!("{err}"))
+            }
+
+            fn div_floor<R: ExchangeRate<A, B>>(
+                &self,
+                rate: &R,
+            ) -> Result<$amount<B>, MonetaryError> {
+                self.exchange_div(rate, Rounding::Floor)
+            }
+
+            fn div_ceil<R: ExchangeRate<A, B>>(
+                &self,
+                rate: &R,
+            ) -> Result<$amount<B>, MonetaryError> {
+                self.exchange_div(rate, Rounding::Ceil)
+            }
+
+            fn exchange<R: ExchangeRate<B, A>>(
+                &self,
+                rate: &R,
+                rounding: Rounding,
+            ) -> Result<$amount<B>, MonetaryError> {
+                let product = rounding.mul_div(
+                    self.0.into(),
+                    rate.decimal256().atomics(),
+                    Decimal256::one().atomics(),
+                );
+                product
+                    .try_into()
+                    .map($amount::new)
+                    .map_err(|_| MonetaryError::Overflow {
+                        operation: OverflowOperation::Mul,
+                        denom: denom_name::<A>(),
+                        lhs: self.to_string(),
+                        rhs: rate.to_string(),
+                    })
+            }
+
+            fn min_out<R: ExchangeRate<B, A>>(
+                &self,
+                rate: &R,
+                max_slippage: Bps,
+            ) -> Result<$amount<B>, MonetaryError> {
+                let out = self.exchange(rate, Rounding::Floor)?;
+                let min = Rounding::Floor.mul_div(
+                    out.0.into(),
+                    (BPS_PER_ONE - max_slippage.u16()).into(),
+                    BPS_PER_ONE.into(),
+                );
+                // At most `out`, so this always fits.
+                Ok($amount::new(min.try_into()?))
+            }
+        }
+
+        impl<A> $amount<A> {
+            fn exchange_div<B, R: ExchangeRate<A, B>>(
+                &self,
+                rate: &R,
+                rounding: Rounding,
+            ) -> Result<$amount<B>, MonetaryError> {
+                let rate_dec = rate.decimal256();
+                // A rate built with `new_unchecked` may still be zero.
+                if rate_dec.is_zero() {
+                    return Err(MonetaryError::DivideByZero {
+                        denom: denom_name::<A>(),
+                        lhs: self.to_string(),
+                    });
+                }
+                let quotient = rounding.mul_div(
+                    self.0.into(),
+                    Decimal256::one().atomics(),
+                    rate_dec.atomics(),
+                );
+                quotient
+                    .try_into()
+                    .map($amount::new)
+                    .map_err(|_| MonetaryError::Overflow {
+                        operation: OverflowOperation::Mul,
+                        denom: denom_name::<A>(),
+                        lhs: self.to_string(),
+                        rhs: format!("1 / {rate}"),
+                    })
+            }
+        }
+    };
 }
 
-impl<A, B> Exchange<A, B> for AmountU128<A> {
-    fn mul_floor(&self, rate: &Rate<B, A>) -> AmountU128<B> {
-        AmountU128::new(self.0.mul_floor(rate.0))
+impl_exchange_amount!(AmountU128);
+impl_exchange_amount!(AmountU256);
+
+/// Rounding applies to the 18th decimal place of the fractional amount.
+impl<A, B> Exchange<A, B> for DecimalAmount<A> {
+    type Output = DecimalAmount<B>;
+
+    fn mul_floor<R: ExchangeRate<B, A>>(&self, rate: &R) -> DecimalAmount<B> {
+        self.exchange(rate, Rounding::Floor)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn mul_ceil<R: ExchangeRate<B, A>>(&self, rate: &R) -> DecimalAmount<B> {
+        self.exchange(rate, Rounding::Ceil)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn div_floor<R: ExchangeRate<A, B>>(
+        &self,
+        rate: &R,
+    ) -> Result<DecimalAmount<B>, MonetaryError> {
+        self.exchange_div(rate, Rounding::Floor)
+    }
+
+    fn div_ceil<R: ExchangeRate<A, B>>(&self, rate: &R) -> Result<DecimalAmount<B>, MonetaryError> {
+        self.exchange_div(rate, Rounding::Ceil)
+    }
+
+    fn exchange<R: ExchangeRate<B, A>>(
+        &self,
+        rate: &R,
+        rounding: Rounding,
+    ) -> Result<DecimalAmount<B>, MonetaryError> {
+        decimal256_mul_div(self.0, rate.decimal256(), Decimal256::one(), rounding)
+            .map(DecimalAmount::new)
+            .ok_or_else(|| MonetaryError::Overflow {
+                operation: OverflowOperation::Mul,
+                denom: denom_name::<A>(),
+                lhs: self.to_string(),
+                rhs: rate.to_string(),
+            })
     }
 
-    fn mul_ceil(&self, rate: &Rate<B, A>) -> AmountU128<B> {
-        AmountU128::new(self.0.mul_ceil(rate.0))
+    fn min_out<R: ExchangeRate<B, A>>(
+        &self,
+        rate: &R,
+        max_slippage: Bps,
+    ) -> Result<DecimalAmount<B>, MonetaryError> {
+        let out = self.exchange(rate, Rounding::Floor)?;
+        let factor = Decimal256::from(Bps::MAX.decimal() - max_slippage.decimal());
+        decimal256_mul_div(out.0, factor, Decimal256::one(), Rounding::Floor)
+            .map(DecimalAmount::new)
+            .ok_or_else(|| MonetaryError::Overflow {
+                operation: OverflowOperation::Mul,
+                denom: denom_name::<B>(),
+                lhs: out.to_string(),
+                rhs: factor.to_string(),
+            })
     }
+}
 
-    fn div_floor(&self, rate: &Rate<A, B>) -> AmountU128<B> {
-        AmountU128::new(self.0.div_floor(rate.0))
+impl<A> DecimalAmount<A> {
+    fn exchange_div<B, R: ExchangeRate<A, B>>(
+        &self,
+        rate: &R,
+        rounding: Rounding,
+    ) -> Result<DecimalAmount<B>, MonetaryError> {
+        let rate_dec = rate.decimal256();
+        if rate_dec.is_zero() {
+            return Err(MonetaryError::DivideByZero {
+                denom: denom_name::<A>(),
+                lhs: self.to_string(),
+            });
+        }
+        decimal256_mul_div(self.0, Decimal256::one(), rate_dec, rounding)
+            .map(DecimalAmount::new)
+            .ok_or_else(|| MonetaryError::Overflow {
+                operation: OverflowOperation::Mul,
+                denom: denom_name::<A>(),
+                lhs: self.to_string(),
+                rhs: format!("1 / {rate}"),
+            })
     }
+}
 
-    fn div_ceil(&self, rate: &Rate<A, B>) -> AmountU128<B> {
-        AmountU128::new(self.0.div_ceil(rate.0))
+/// Computes `value * mul / div` on the decimals' atomics, rounding the last decimal place.
+/// Returns `None` if the result overflows.
+fn decimal256_mul_div(
+    value: Decimal256,
+    mul: Decimal256,
+    div: Decimal256,
+    rounding: Rounding,
+) -> Option<Decimal256> {
+    let atomics = rounding.mul_div(value.atomics(), mul.atomics(), div.atomics());
+    atomics.try_into().ok().map(Decimal256::new)
+}
+
+impl<A: PartialEq, B: PartialEq> PartialOrd for Rate<A, B> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.0.cmp(&other.0))
+    }
+}
+
+impl<A: Eq, B: Eq> Ord for Rate<A, B> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
     }
 }
 
@@ -136,6 +532,7 @@ impl<A, B> std::fmt::Display for Rate<A, B> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::Denom;
     use cosmwasm_std::Decimal;
     use monetary_macros::denom;
 
@@ -154,4 +551,281 @@ mod test {
         let deserialized: Rate<A, B> = serde_json_wasm::from_str(&serialized).unwrap();
         assert_eq!(rate, deserialized);
     }
+
+    #[test]
+    fn precise_scaling_does_not_panic() {
+        let rate = Decimal::from_ratio(10u128.pow(10), 1u128);
+        assert!(Rate::new_precise(rate, &Precise::new(A, 18), &Precise::new(B, 0)).is_none());
+        assert!(Rate::new_precise(rate, &Precise::new(A, 0), &Precise::new(B, 60)).is_none());
+        assert!(Rate::new_precise(rate, &Precise::new(A, 60), &Precise::new(B, 0)).is_none());
+
+        let scaled = Rate::new_precise(rate, &Precise::new(A, 8), &Precise::new(B, 0)).unwrap();
+        assert_eq!(scaled.rate(), Decimal::from_ratio(10u128.pow(18), 1u128));
+        let scaled = Rate::new_precise(rate, &Precise::new(A, 0), &Precise::new(B, 8)).unwrap();
+        assert_eq!(scaled.rate(), Decimal::percent(10_000));
+    }
+
+    #[test]
+    fn exchange_rounding() {
+        // 1 A = 2.5 B
+        let rate = Rate::<B, A>::new(Decimal::percent(250)).unwrap();
+        let exchange = |amount: u128, rounding| {
+            AmountU128::<A>::new(Uint128::new(amount))
+                .exchange(&rate, rounding)
+                .unwrap()
+                .u128()
+        };
+        assert_eq!(exchange(3, Rounding::Floor), 7);
+        assert_eq!(exchange(3, Rounding::Ceil), 8);
+        assert_eq!(exchange(3, Rounding::HalfUp), 8);
+        assert_eq!(exchange(3, Rounding::HalfEven), 8);
+        assert_eq!(exchange(1, Rounding::HalfEven), 2);
+        assert_eq!(exchange(1, Rounding::TowardZero), 2);
+
+        let err = AmountU128::<A>::new(Uint128::MAX)
+            .exchange(&rate, Rounding::Floor)
+            .unwrap_err();
+        assert!(matches!(err, MonetaryError::Overflow { .. }));
+    }
+
+    #[test]
+    fn division_does_not_panic() {
+        // 1 B = 0.000000000000000001 A
+        let tiny = Rate::<A, B>::new(Decimal::new(Uint128::one())).unwrap();
+        let max = AmountU128::<A>::new(Uint128::MAX);
+        assert!(matches!(
+            max.div_floor(&tiny).unwrap_err(),
+            MonetaryError::Overflow { .. }
+        ));
+        assert!(matches!(
+            DecimalAmount::<A>::new(Decimal256::MAX)
+                .div_ceil(&tiny)
+                .unwrap_err(),
+            MonetaryError::Overflow { .. }
+        ));
+
+        let zero = unsafe { Rate::<A, B>::new_unchecked(Decimal::zero()) };
+        assert_eq!(
+            max.div_floor(&zero).unwrap_err(),
+            MonetaryError::DivideByZero {
+                denom: denom_name::<A>(),
+                lhs: max.to_string(),
+            }
+        );
+        assert!(matches!(
+            DecimalAmount::from(max).div_floor(&zero).unwrap_err(),
+            MonetaryError::DivideByZero { .. }
+        ));
+        assert!(zero.inv().is_err());
+
+        // The inverse of anything above 10^18 rounds to zero.
+        let huge = Rate::<A, B>::new(Decimal::from_ratio(10u128.pow(19), 1u128)).unwrap();
+        assert_eq!(
+            huge.inv().unwrap_err(),
+            MonetaryError::ZeroRate {
+                numerator: denom_name::<B>(),
+                denominator: denom_name::<A>(),
+            }
+        );
+
+        let rate = Rate::<A, B>::new(Decimal::percent(40)).unwrap();
+        assert_eq!(rate.inv().unwrap().rate(), Decimal::percent(250));
+        let amount = AmountU128::<A>::new(Uint128::new(3));
+        assert_eq!(amount.div_floor(&rate).unwrap().u128(), 7);
+        assert_eq!(amount.div_ceil(&rate).unwrap().u128(), 8);
+    }
+
+    #[denom]
+    pub struct C;
+
+    #[test]
+    fn decimal_adjustments_stay_non_zero() {
+        let rate = Rate::<A, B>::new(Decimal::percent(40)).unwrap();
+        assert_eq!(
+            rate.add_decimal(Decimal::percent(10)).unwrap().rate(),
+            Decimal::percent(50)
+        );
+        assert_eq!(
+            rate.sub_decimal(Decimal::percent(10)).unwrap().rate(),
+            Decimal::percent(30)
+        );
+        assert_eq!(
+            rate.sub_decimal(Decimal::percent(40)).unwrap_err(),
+            MonetaryError::ZeroRate {
+                numerator: denom_name::<A>(),
+                denominator: denom_name::<B>(),
+            }
+        );
+        assert!(matches!(
+            rate.sub_decimal(Decimal::percent(41)).unwrap_err(),
+            MonetaryError::Std(_)
+        ));
+        assert!(matches!(
+            rate.add_decimal(Decimal::MAX).unwrap_err(),
+            MonetaryError::Std(_)
+        ));
+    }
+
+    #[test]
+    fn checked_composition() {
+        let a_per_b = Rate::<A, B>::new(Decimal::percent(250)).unwrap();
+        let b_per_c = Rate::<B, C>::new(Decimal::percent(40)).unwrap();
+        let c_per_b = b_per_c.inv().unwrap();
+        assert_eq!(a_per_b.checked_mul(b_per_c).unwrap().rate(), Decimal::one());
+        assert_eq!(a_per_b.checked_div(c_per_b).unwrap().rate(), Decimal::one());
+        assert_eq!((a_per_b * b_per_c).rate(), Decimal::one());
+        assert_eq!((a_per_b / c_per_b).rate(), Decimal::one());
+
+        let dust = Rate::<A, B>::new(Decimal::new(Uint128::one())).unwrap();
+        let small = Rate::<B, C>::new(Decimal::percent(10)).unwrap();
+        assert_eq!(
+            dust.checked_mul(small).unwrap_err(),
+            MonetaryError::ZeroRate {
+                numerator: denom_name::<A>(),
+                denominator: denom_name::<C>(),
+            }
+        );
+        assert!(matches!(
+            dust.checked_div(small.inv().unwrap()).unwrap_err(),
+            MonetaryError::ZeroRate { .. }
+        ));
+
+        let huge = Rate::<A, B>::new(Decimal::MAX).unwrap();
+        let two = Rate::<B, C>::new(Decimal::percent(200)).unwrap();
+        assert!(matches!(
+            huge.checked_mul(two).unwrap_err(),
+            MonetaryError::Overflow { .. }
+        ));
+        assert!(matches!(
+            huge.checked_div(two.inv().unwrap()).unwrap_err(),
+            MonetaryError::Overflow { .. }
+        ));
+    }
+
+    #[test]
+    fn deviation() {
+        let oracle = Rate::<A, B>::new(Decimal::percent(200)).unwrap();
+        let limit = Rate::<A, B>::new(Decimal::percent(197)).unwrap();
+        assert_eq!(limit.deviation_from(&oracle), Decimal::bps(150));
+        assert_eq!(
+            oracle.deviation_from(&limit),
+            Decimal::from_ratio(3u128, 197u128)
+        );
+        assert_eq!(oracle.deviation_from(&oracle), Decimal::zero());
+
+        limit
+            .ensure_within_bps(&oracle, Bps::new(150).unwrap())
+            .unwrap();
+        assert_eq!(
+            limit
+                .ensure_within_bps(&oracle, Bps::new(149).unwrap())
+                .unwrap_err(),
+            MonetaryError::ExcessiveDeviation {
+                rate: "1.97".to_string(),
+                reference: "2".to_string(),
+                max_deviation: "149bps".to_string(),
+            }
+        );
+
+        let dust = Rate::<A, B>::new(Decimal::new(Uint128::one())).unwrap();
+        let huge = Rate::<A, B>::new(Decimal::MAX).unwrap();
+        assert_eq!(huge.deviation_from(&dust), Decimal::MAX);
+    }
+
+    #[test]
+    fn from_amounts() {
+        let amount_a = |value: u128| AmountU128::<A>::new(Uint128::new(value));
+        let amount_b = |value: u128| AmountU128::<B>::new(Uint128::new(value));
+
+        let rate = Rate::from_amounts(amount_a(5_000), amount_b(2_000)).unwrap();
+        assert_eq!(rate.rate(), Decimal::percent(250));
+
+        assert_eq!(
+            Rate::from_amounts(amount_a(5_000), amount_b(0)).unwrap_err(),
+            MonetaryError::DivideByZero {
+                denom: denom_name::<A>(),
+                lhs: "5000".to_string(),
+            }
+        );
+        assert_eq!(
+            Rate::from_amounts(amount_a(0), amount_b(2_000)).unwrap_err(),
+            MonetaryError::ZeroRate {
+                numerator: denom_name::<A>(),
+                denominator: denom_name::<B>(),
+            }
+        );
+        assert!(matches!(
+            Rate::from_amounts(amount_a(1), amount_b(u128::MAX)).unwrap_err(),
+            MonetaryError::ZeroRate { .. }
+        ));
+        assert!(matches!(
+            Rate::from_amounts(amount_a(u128::MAX), amount_b(1)).unwrap_err(),
+            MonetaryError::Overflow { .. }
+        ));
+
+        let coin_a = CheckedCoin::new(Denom::new("a"), amount_a(5_000));
+        let coin_b = CheckedCoin::new(Denom::new("b"), amount_b(2_000));
+        assert_eq!(Rate::from_coins(&coin_a, &coin_b).unwrap(), rate);
+    }
+
+    #[test]
+    fn ordering() {
+        let rate = |percent| Rate::<A, B>::new(Decimal::percent(percent)).unwrap();
+        assert!(rate(100) < rate(101));
+        assert_eq!(rate(100).max(rate(101)), rate(101));
+        assert_eq!(rate(100).min(rate(101)), rate(100));
+        assert_eq!(rate(150).clamp(rate(100), rate(120)), rate(120));
+
+        rate(100).ensure_at_least(&rate(100)).unwrap();
+        rate(100).ensure_at_most(&rate(100)).unwrap();
+        assert_eq!(
+            rate(99).ensure_at_least(&rate(100)).unwrap_err(),
+            MonetaryError::RateTooLow {
+                rate: "0.99".to_string(),
+                min: "1".to_string(),
+            }
+        );
+        assert_eq!(
+            rate(101).ensure_at_most(&rate(100)).unwrap_err(),
+            MonetaryError::RateTooHigh {
+                rate: "1.01".to_string(),
+                max: "1".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn min_out() {
+        // 1 A = 2.5 B
+        let rate = Rate::<B, A>::new(Decimal::percent(250)).unwrap();
+        let amount = AmountU128::<A>::new(Uint128::new(1_000));
+        let slippage = Bps::new(50).unwrap();
+        assert_eq!(amount.min_out(&rate, slippage).unwrap().u128(), 2_487);
+        assert_eq!(amount.min_out(&rate, Bps::ZERO).unwrap().u128(), 2_500);
+        assert!(amount.min_out(&rate, Bps::MAX).unwrap().is_zero());
+
+        let decimal = DecimalAmount::from(amount);
+        assert_eq!(
+            decimal.min_out(&rate, slippage).unwrap().decimal256(),
+            Decimal256::percent(248_750)
+        );
+
+        AmountU128::<A>::new(Uint128::MAX)
+            .min_out(&rate, slippage)
+            .unwrap_err();
+    }
+
+    #[test]
+    #[should_panic(expected = "rounds to zero")]
+    fn operators_never_produce_zero() {
+        let dust = Rate::<A, B>::new(Decimal::new(Uint128::one())).unwrap();
+        let _ = dust * Rate::<B, C>::new(Decimal::percent(10)).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Mul overflow")]
+    fn mul_floor_overflow_panics() {
+        let two = Rate::<B, A>::new(Decimal::percent(200)).unwrap();
+        let _ = AmountU128::<A>::new(Uint128::MAX).mul_floor(&two);
+    }
 }
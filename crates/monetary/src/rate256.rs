@@ -0,0 +1,266 @@
+use std::{
+    cmp::Ordering,
+    marker::PhantomData,
+    ops::{Div, Mul},
+};
+
+use cosmwasm_std::{CheckedFromRatioError, Decimal256, Fraction, OverflowOperation, Uint256};
+
+use crate::{zero_rate, ExchangeRate, MonetaryError, Precise, Rate};
+
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent, bound = ""))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+/// A rate representing A / B, backed by a [`Decimal256`].
+///
+/// Use this instead of [`Rate`] when composing long chains of rates, e.g. SHIB/USD × USD/BTC,
+/// whose intermediate values can exceed the range of a [`cosmwasm_std::Decimal`].
+pub struct Rate256<A, B>(
+    Decimal256,
+    #[cfg_attr(feature = "serde", serde(skip))] PhantomData<(A, B)>,
+);
+
+impl<A, B> Rate256<A, B> {
+    /// Create a new rate from a decimal.
+    /// Returns None if the rate is zero.
+    pub fn new(rate: Decimal256) -> Option<Self> {
+        if rate.is_zero() {
+            None
+        } else {
+            Some(Rate256(rate, PhantomData))
+        }
+    }
+
+    /// Create a new rate from a decimal without checking if it is zero.
+    ///
+    /// # Safety
+    /// The user ensures that the rate is non-zero.
+    pub unsafe fn new_unchecked(rate: Decimal256) -> Self {
+        Rate256(rate, PhantomData)
+    }
+
+    pub fn rate(&self) -> Decimal256 {
+        self.0
+    }
+
+    /// Returns the inverse rate, B / A.
+    /// Errors if the inverse is too small to be represented, i.e. rounds to zero.
+    pub fn inv(&self) -> Result<Rate256<B, A>, MonetaryError> {
+        self.0
+            .inv()
+            .and_then(Rate256::new)
+            .ok_or_else(zero_rate::<B, A>)
+    }
+}
+
+impl<N, S> Rate256<N, S> {
+    /// Composes `N / S * S / D = N / D`.
+    /// Errors if the product overflows or is too small to be represented.
+    pub fn checked_mul<D>(self, rhs: Rate256<S, D>) -> Result<Rate256<N, D>, MonetaryError> {
+        let product = self
+            .0
+            .checked_mul(rhs.0)
+            .map_err(|_| MonetaryError::Overflow {
+                operation: OverflowOperation::Mul,
+                denom: String::new(),
+                lhs: self.to_string(),
+                rhs: rhs.to_string(),
+            })?;
+        Rate256::new(product).ok_or_else(zero_rate::<N, D>)
+    }
+
+    /// Composes `(N / S) / (D / S) = N / D`.
+    /// Errors if the quotient overflows or is too small to be represented.
+    pub fn checked_div<D>(self, rhs: Rate256<D, S>) -> Result<Rate256<N, D>, MonetaryError> {
+        let quotient = self.0.checked_div(rhs.0).map_err(|err| match err {
+            CheckedFromRatioError::DivideByZero => MonetaryError::DivideByZero {
+                denom: String::new(),
+                lhs: self.to_string(),
+            },
+            CheckedFromRatioError::Overflow => MonetaryError::Overflow {
+                operation: OverflowOperation::Mul,
+                denom: String::new(),
+                lhs: self.to_string(),
+                rhs: format!("1 / {rhs}"),
+            },
+        })?;
+        Rate256::new(quotient).ok_or_else(zero_rate::<N, D>)
+    }
+}
+
+impl<A, B> Rate256<Precise<A>, Precise<B>> {
+    /// Creates a rate between base units from a rate between display units.
+    /// Returns None if the scaled rate is zero or overflows.
+    pub fn new_precise(rate: Decimal256, from: &Precise<A>, to: &Precise<B>) -> Option<Self> {
+        Self::new(scale_rate256(rate, from.decimals(), to.decimals())?)
+    }
+}
+
+fn scale_rate256(rate: Decimal256, a_dec: u8, b_dec: u8) -> Option<Decimal256> {
+    let factor = Uint256::from(10u8)
+        .checked_pow(a_dec.abs_diff(b_dec) as u32)
+        .ok()?;
+    let atomics = match a_dec.cmp(&b_dec) {
+        Ordering::Equal => rate.atomics(),
+        Ordering::Greater => rate.atomics().checked_mul(factor).ok()?,
+        Ordering::Less => rate.atomics().checked_div(factor).ok()?,
+    };
+    Some(Decimal256::new(atomics))
+}
+
+/// Every [`Rate`] is representable as a [`Rate256`] without loss.
+impl<A, B> From<Rate<A, B>> for Rate256<A, B> {
+    fn from(val: Rate<A, B>) -> Self {
+        Rate256(val.rate().into(), PhantomData)
+    }
+}
+
+impl<N, D> ExchangeRate<N, D> for Rate256<N, D> {
+    fn decimal256(&self) -> Decimal256 {
+        self.0
+    }
+}
+
+/// # Panics
+/// Panics if the product overflows or rounds to zero. Use [`Rate256::checked_mul`] to handle
+/// these cases.
+impl<N, S, D> Mul<Rate256<S, D>> for Rate256<N, S> {
+    type Output = Rate256<N, D>;
+
+    fn mul(self, rhs: Rate256<S, D>) -> Self::Output {
+        // N / S * S / D = N / D
+        self.checked_mul(rhs).unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+/// # Panics
+/// Panics if the quotient overflows or rounds to zero. Use [`Rate256::checked_div`] to handle
+/// these cases.
+impl<N, S, D> Div<Rate256<D, S>> for Rate256<N, S> {
+    type Output = Rate256<N, D>;
+
+    fn div(self, rhs: Rate256<D, S>) -> Self::Output {
+        // (N / S) / (D / S) = N / D
+        self.checked_div(rhs).unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+impl<A: PartialEq, B: PartialEq> PartialOrd for Rate256<A, B> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.0.cmp(&other.0))
+    }
+}
+
+impl<A: Eq, B: Eq> Ord for Rate256<A, B> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<A, B> std::fmt::Display for Rate256<A, B> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::{Decimal, Decimal256, Uint128, Uint256};
+    use monetary_macros::denom;
+
+    use crate::{AmountU128, AmountU256, Exchange, MonetaryError, Precise, Rate, Rate256};
+
+    #[denom]
+    pub struct Shib;
+
+    #[denom]
+    pub struct Usd;
+
+    #[denom]
+    pub struct Btc;
+
+    #[test]
+    fn long_chains() {
+        // 1 USD = 100000 SHIB, so 10^17 SHIB atoms (18 decimals) per uusd
+        let shib_per_usd =
+            Rate::<Shib, Usd>::new(Decimal::from_ratio(10u128.pow(17), 1u128)).unwrap();
+        // 1 BTC = 10^6 USD, so 10^4 uusd per sat
+        let usd_per_btc = Rate::<Usd, Btc>::new(Decimal::from_ratio(10_000u128, 1u128)).unwrap();
+
+        // 10^21 SHIB atoms per sat is out of range for a Decimal
+        assert!(matches!(
+            shib_per_usd.checked_mul(usd_per_btc).unwrap_err(),
+            MonetaryError::Overflow { .. }
+        ));
+
+        let shib_per_btc = Rate256::from(shib_per_usd) * Rate256::from(usd_per_btc);
+        assert_eq!(
+            shib_per_btc.rate(),
+            Decimal256::from_ratio(10u128.pow(21), 1u128)
+        );
+        assert_eq!(
+            (shib_per_btc / Rate256::from(usd_per_btc)).rate(),
+            Decimal256::from(shib_per_usd.rate())
+        );
+
+        let one_btc = AmountU128::<Btc>::new(Uint128::new(100_000_000));
+        let shib = one_btc.mul_floor(&shib_per_btc);
+        assert_eq!(shib.u128(), 10u128.pow(29));
+        assert_eq!(shib.div_floor(&shib_per_btc).unwrap(), one_btc);
+
+        let wide = AmountU256::<Btc>::new(Uint256::from(u128::MAX));
+        assert_eq!(
+            wide.exchange(&shib_per_btc, crate::Rounding::Floor)
+                .unwrap()
+                .uint256(),
+            Uint256::from(u128::MAX) * Uint256::from(10u128.pow(21))
+        );
+        assert!(matches!(
+            AmountU128::<Btc>::new(Uint128::MAX)
+                .exchange(&shib_per_btc, crate::Rounding::Floor)
+                .unwrap_err(),
+            MonetaryError::Overflow { .. }
+        ));
+    }
+
+    #[test]
+    fn inverse_and_precision() {
+        let rate = Rate256::<Usd, Btc>::new(Decimal256::percent(40)).unwrap();
+        assert_eq!(rate.inv().unwrap().rate(), Decimal256::percent(250));
+
+        let huge = Rate256::<Usd, Btc>::new(Decimal256::from_ratio(10u128.pow(19), 1u128));
+        assert!(matches!(
+            huge.unwrap().inv().unwrap_err(),
+            MonetaryError::ZeroRate { .. }
+        ));
+
+        // 1 BTC (8 decimals) = 60000 USD (6 decimals)
+        let display = Decimal256::from_ratio(60_000u128, 1u128);
+        let rate = Rate256::new_precise(display, &Precise::new(Usd, 6), &Precise::new(Btc, 8));
+        assert_eq!(rate.unwrap().rate(), Decimal256::from_ratio(600u128, 1u128));
+        let legacy = Rate::new_precise(
+            Decimal::from_ratio(60_000u128, 1u128),
+            &Precise::new(Usd, 6),
+            &Precise::new(Btc, 8),
+        );
+        assert_eq!(Rate256::from(legacy.unwrap()).rate(), rate.unwrap().rate());
+
+        assert!(Rate256::new_precise(
+            Decimal256::MAX,
+            &Precise::new(Usd, 18),
+            &Precise::new(Btc, 0)
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn serialization() {
+        let rate = Rate256::<Usd, Btc>::new(Decimal256::percent(50)).unwrap();
+        let serialized = serde_json_wasm::to_string(&rate).unwrap();
+        assert_eq!(serialized, r#""0.5""#);
+
+        let deserialized: Rate256<Usd, Btc> = serde_json_wasm::from_str(&serialized).unwrap();
+        assert_eq!(rate, deserialized);
+    }
+}
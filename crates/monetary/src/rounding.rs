@@ -0,0 +1,98 @@
+use cosmwasm_std::{Uint256, Uint512};
+
+/// How to round a result that can't be represented exactly.
+///
+/// Every conversion that may lose precision — [`crate::AmountU128::dec_mul`],
+/// [`crate::Exchange::exchange`] and [`crate::AmountU128::rescale`] — takes a `Rounding`, so a
+/// protocol's rounding policy can be configured and audited in one place.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Rounding {
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceil,
+    /// Round to the nearest value, with halves rounded away from zero.
+    HalfUp,
+    /// Round to the nearest value, with halves rounded to the even neighbour.
+    HalfEven,
+    /// Discard the fractional part.
+    TowardZero,
+}
+
+impl Rounding {
+    /// Divides `numerator` by a non-zero `denominator`, rounding the quotient.
+    pub(crate) fn div(self, numerator: Uint512, denominator: Uint512) -> Uint512 {
+        let quotient = numerator / denominator;
+        let remainder = numerator % denominator;
+        if remainder.is_zero() {
+            return quotient;
+        }
+
+        // Compared this way round to avoid overflowing `2 * remainder`.
+        let above_half = remainder > denominator - remainder;
+        let is_half = remainder == denominator - remainder;
+        let round_up = match self {
+            // All inputs are unsigned, so flooring and truncating coincide.
+            Rounding::Floor | Rounding::TowardZero => false,
+            Rounding::Ceil => true,
+            Rounding::HalfUp => above_half || is_half,
+            Rounding::HalfEven => {
+                above_half || (is_half && quotient % Uint512::from(2u8) == Uint512::one())
+            }
+        };
+        if round_up {
+            quotient + Uint512::one()
+        } else {
+            quotient
+        }
+    }
+
+    /// Computes `value * numerator / denominator` without intermediate overflow, rounding the
+    /// result. `denominator` must be non-zero.
+    pub(crate) fn mul_div(
+        self,
+        value: Uint256,
+        numerator: Uint256,
+        denominator: Uint256,
+    ) -> Uint512 {
+        self.div(value.full_mul(numerator), denominator.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::Uint512;
+
+    use super::Rounding;
+
+    #[test]
+    fn rounding_modes() {
+        let div = |rounding: Rounding, n: u128, d: u128| {
+            rounding.div(Uint512::from(n), Uint512::from(d)).to_string()
+        };
+        let cases = [
+            // (n, d, floor, ceil, half_up, half_even)
+            (10, 4, "2", "3", "3", "2"),
+            (14, 4, "3", "4", "4", "4"),
+            (9, 4, "2", "3", "2", "2"),
+            (11, 4, "2", "3", "3", "3"),
+            (12, 4, "3", "3", "3", "3"),
+        ];
+        for (n, d, floor, ceil, half_up, half_even) in cases {
+            assert_eq!(div(Rounding::Floor, n, d), floor);
+            assert_eq!(div(Rounding::TowardZero, n, d), floor);
+            assert_eq!(div(Rounding::Ceil, n, d), ceil);
+            assert_eq!(div(Rounding::HalfUp, n, d), half_up);
+            assert_eq!(div(Rounding::HalfEven, n, d), half_even);
+        }
+    }
+
+    #[test]
+    fn serialization() {
+        let serialized = serde_json_wasm::to_string(&Rounding::HalfEven).unwrap();
+        assert_eq!(serialized, r#""half_even""#);
+    }
+}
@@ -0,0 +1,225 @@
+use std::marker::PhantomData;
+
+use cosmwasm_std::{Int256, OverflowError, Uint256};
+
+use crate::{denom_name, AmountU128, AmountU256, AmountU64, MonetaryError};
+
+/// A signed amount of `T`, e.g. a realised PnL, funding payment or balance delta.
+///
+/// Backed by an [`Int256`], so every [`AmountU128`] (and the difference of any two of them)
+/// is representable without overflow.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub struct SignedAmount<T>(
+    pub(crate) Int256,
+    #[cfg_attr(feature = "serde", serde(skip))] PhantomData<T>,
+);
+
+impl<T> SignedAmount<T> {
+    #[inline]
+    pub const fn new(amount: Int256) -> Self {
+        SignedAmount(amount, PhantomData)
+    }
+
+    #[inline]
+    pub const fn int256(&self) -> Int256 {
+        self.0
+    }
+
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::new(Int256::zero())
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_negative(&self) -> bool {
+        self.0.is_negative()
+    }
+
+    /// Returns the absolute value.
+    ///
+    /// # Panics
+    /// Panics if `self` is [`Int256::MIN`], which cannot be reached from unsigned amounts.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn abs(self) -> Self {
+        Self::new(self.0.abs())
+    }
+
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub const fn unsigned_abs(self) -> AmountU256<T> {
+        AmountU256::new(self.0.unsigned_abs())
+    }
+
+    pub fn checked_add(self, other: Self) -> Result<Self, OverflowError> {
+        Ok(Self::new(self.0.checked_add(other.0)?))
+    }
+
+    pub fn checked_sub(self, other: Self) -> Result<Self, OverflowError> {
+        Ok(Self::new(self.0.checked_sub(other.0)?))
+    }
+
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn saturating_add(self, other: Self) -> Self {
+        Self::new(self.0.saturating_add(other.0))
+    }
+
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn saturating_sub(self, other: Self) -> Self {
+        Self::new(self.0.saturating_sub(other.0))
+    }
+}
+
+impl<T> AmountU128<T> {
+    /// Subtracts `other` from `self`, producing a possibly negative delta.
+    #[must_use = "this returns the result of the operation, without modifying the original"]
+    pub fn signed_sub(self, other: Self) -> SignedAmount<T> {
+        SignedAmount::from(self) - SignedAmount::from(other)
+    }
+}
+
+impl<T> From<AmountU64<T>> for SignedAmount<T> {
+    fn from(val: AmountU64<T>) -> Self {
+        Self::new(val.0.into())
+    }
+}
+
+impl<T> From<AmountU128<T>> for SignedAmount<T> {
+    fn from(val: AmountU128<T>) -> Self {
+        Self::new(val.0.into())
+    }
+}
+
+impl<T> TryFrom<SignedAmount<T>> for AmountU128<T> {
+    type Error = MonetaryError;
+
+    fn try_from(val: SignedAmount<T>) -> Result<Self, Self::Error> {
+        if val.is_negative() {
+            return Err(MonetaryError::NegativeAmount {
+                denom: denom_name::<T>(),
+                amount: val.to_string(),
+            });
+        }
+        Ok(AmountU128::new(val.0.try_into()?))
+    }
+}
+
+impl<T> TryFrom<SignedAmount<T>> for AmountU256<T> {
+    type Error = MonetaryError;
+
+    fn try_from(val: SignedAmount<T>) -> Result<Self, Self::Error> {
+        if val.is_negative() {
+            return Err(MonetaryError::NegativeAmount {
+                denom: denom_name::<T>(),
+                amount: val.to_string(),
+            });
+        }
+        Ok(AmountU256::new(Uint256::try_from(val.0)?))
+    }
+}
+
+impl<T> std::ops::Add for SignedAmount<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.0 + rhs.0)
+    }
+}
+
+impl<T> std::ops::Sub for SignedAmount<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.0 - rhs.0)
+    }
+}
+
+impl<T> std::ops::Neg for SignedAmount<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.0)
+    }
+}
+
+impl<T> std::ops::AddAssign for SignedAmount<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl<T> std::ops::SubAssign for SignedAmount<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl<T> std::fmt::Display for SignedAmount<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<T: PartialEq> PartialOrd for SignedAmount<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.0.cmp(&other.0))
+    }
+}
+
+impl<T: Eq> Ord for SignedAmount<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<T: Default> Default for SignedAmount<T> {
+    fn default() -> Self {
+        Self::new(Int256::default())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::Int256;
+
+    use crate::test::{amount, Usd};
+    use crate::{AmountU128, AmountU256, MonetaryError, SignedAmount};
+
+    #[test]
+    fn deltas() {
+        let pnl = amount(100).signed_sub(amount(250));
+        assert!(pnl.is_negative());
+        assert_eq!(pnl, SignedAmount::new(Int256::from(-150i128)));
+        assert_eq!(pnl.abs(), SignedAmount::from(amount(150)));
+        assert_eq!(pnl.unsigned_abs(), AmountU256::from(amount(150)));
+
+        let max = amount(u128::MAX).signed_sub(AmountU128::zero());
+        assert_eq!(AmountU128::try_from(max).unwrap(), amount(u128::MAX));
+        assert_eq!(
+            AmountU128::try_from(-pnl + pnl + pnl).unwrap_err(),
+            MonetaryError::NegativeAmount {
+                denom: std::any::type_name::<Usd>().to_string(),
+                amount: "-150".to_string(),
+            }
+        );
+        AmountU128::try_from(max + max).unwrap_err();
+    }
+
+    #[test]
+    fn serialization() {
+        let a = amount(5).signed_sub(amount(12));
+        let serialized = serde_json_wasm::to_string(&a).unwrap();
+        assert_eq!(serialized, r#""-7""#);
+
+        let b: SignedAmount<Usd> = serde_json_wasm::from_str(&serialized).unwrap();
+        assert_eq!(a, b);
+    }
+}
@@ -0,0 +1,158 @@
+use cosmwasm_std::{Decimal, OverflowOperation, Uint256};
+
+use crate::{zero_rate, AmountU128, Bps, Exchange, MonetaryError, Rate, Rounding, BPS_PER_ONE};
+
+/// A two-sided quote for `B` in units of `A`, with the invariant `bid <= ask`.
+///
+/// The quoting side buys `B` at the bid and sells it at the ask. [`Spread::sell`] and
+/// [`Spread::buy`] pick the side and rounding direction that favour the quoting side, so a
+/// counterparty can never receive more than the spread allows.
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(try_from = "UncheckedSpread<A, B>", bound = "")
+)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Spread<A, B> {
+    bid: Rate<A, B>,
+    ask: Rate<A, B>,
+}
+
+impl<A, B> Spread<A, B> {
+    /// Creates a spread from explicit rates. Errors if `bid > ask`.
+    pub fn new(bid: Rate<A, B>, ask: Rate<A, B>) -> Result<Self, MonetaryError> {
+        if bid.rate() > ask.rate() {
+            return Err(MonetaryError::InvalidSpread {
+                bid: bid.to_string(),
+                ask: ask.to_string(),
+            });
+        }
+        Ok(Spread { bid, ask })
+    }
+
+    /// Creates a spread `bps` either side of `mid`. The bid is rounded down and the ask up.
+    /// Errors if the bid rounds to zero or the ask overflows.
+    pub fn from_mid(mid: Rate<A, B>, bps: Bps) -> Result<Self, MonetaryError> {
+        let scale = |factor: u16, rounding: Rounding| {
+            let atomics = rounding.mul_div(
+                mid.rate().atomics().into(),
+                Uint256::from(factor),
+                Uint256::from(BPS_PER_ONE),
+            );
+            atomics.try_into().ok().map(Decimal::new)
+        };
+
+        let bid = scale(BPS_PER_ONE - bps.u16(), Rounding::Floor)
+            .and_then(Rate::new)
+            .ok_or_else(zero_rate::<A, B>)?;
+        let ask = scale(BPS_PER_ONE + bps.u16(), Rounding::Ceil)
+            .and_then(Rate::new)
+            .ok_or_else(|| MonetaryError::Overflow {
+                operation: OverflowOperation::Mul,
+                denom: String::new(),
+                lhs: mid.to_string(),
+                rhs: bps.to_string(),
+            })?;
+        Ok(Spread { bid, ask })
+    }
+
+    pub fn bid(&self) -> &Rate<A, B> {
+        &self.bid
+    }
+
+    pub fn ask(&self) -> &Rate<A, B> {
+        &self.ask
+    }
+
+    /// Sells `B` for `amount_a`, at the ask and rounding down.
+    pub fn sell(&self, amount_a: AmountU128<A>) -> Result<AmountU128<B>, MonetaryError> {
+        amount_a.div_floor(&self.ask)
+    }
+
+    /// Buys `amount_b` for `A`, at the bid and rounding down.
+    pub fn buy(&self, amount_b: AmountU128<B>) -> Result<AmountU128<A>, MonetaryError> {
+        amount_b.exchange(&self.bid, Rounding::Floor)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(bound = "")]
+struct UncheckedSpread<A, B> {
+    bid: Rate<A, B>,
+    ask: Rate<A, B>,
+}
+
+#[cfg(feature = "serde")]
+impl<A, B> TryFrom<UncheckedSpread<A, B>> for Spread<A, B> {
+    type Error = MonetaryError;
+
+    fn try_from(val: UncheckedSpread<A, B>) -> Result<Self, Self::Error> {
+        Spread::new(val.bid, val.ask)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::{Decimal, Uint128};
+
+    use crate::test::{rate, Atom, Usd};
+    use crate::{AmountU128, Bps, MonetaryError, Rate, Spread};
+
+    #[test]
+    fn quoting() {
+        let spread = Spread::new(rate(990), rate(1010)).unwrap();
+
+        // 100 USD buys 9.9 ATOM at the ask, rounded down
+        let atom = spread.sell(AmountU128::new(Uint128::new(100))).unwrap();
+        assert_eq!(atom.u128(), 9);
+
+        // 3 ATOM sell for 29.7 USD at the bid, rounded down
+        let usd = spread.buy(AmountU128::new(Uint128::new(3))).unwrap();
+        assert_eq!(usd.u128(), 29);
+
+        assert_eq!(
+            Spread::new(rate(1010), rate(990)).unwrap_err(),
+            MonetaryError::InvalidSpread {
+                bid: "10.1".to_string(),
+                ask: "9.9".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn from_mid() {
+        let spread = Spread::from_mid(rate(1000), Bps::new(100).unwrap()).unwrap();
+        assert_eq!(spread.bid(), &rate(990));
+        assert_eq!(spread.ask(), &rate(1010));
+
+        let dust = Rate::<Usd, Atom>::new(Decimal::new(Uint128::new(3))).unwrap();
+        let spread = Spread::from_mid(dust, Bps::new(5000).unwrap()).unwrap();
+        assert_eq!(spread.bid().rate(), Decimal::new(Uint128::one()));
+        assert_eq!(spread.ask().rate(), Decimal::new(Uint128::new(5)));
+
+        assert!(matches!(
+            Spread::from_mid(rate(1000), Bps::MAX).unwrap_err(),
+            MonetaryError::ZeroRate { .. }
+        ));
+        assert!(matches!(
+            Spread::<Usd, Atom>::from_mid(Rate::new(Decimal::MAX).unwrap(), Bps::new(1).unwrap())
+                .unwrap_err(),
+            MonetaryError::Overflow { .. }
+        ));
+    }
+
+    #[test]
+    fn serialization() {
+        let spread = Spread::new(rate(990), rate(1010)).unwrap();
+        let serialized = serde_json_wasm::to_string(&spread).unwrap();
+        assert_eq!(serialized, r#"{"bid":"9.9","ask":"10.1"}"#);
+
+        let deserialized: Spread<Usd, Atom> = serde_json_wasm::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, spread);
+
+        serde_json_wasm::from_str::<Spread<Usd, Atom>>(r#"{"bid":"10.1","ask":"9.9"}"#)
+            .unwrap_err();
+    }
+}
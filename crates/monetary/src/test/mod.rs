@@ -11,14 +11,14 @@ fn do_something() {
 
     let amount = AmountU128::new(Uint128::from(1_000_000u128));
     let rate = Rate::<A, B>::new(Decimal::percent(50)).unwrap();
-    let out = amount.div_floor(&rate);
+    let out = amount.div_floor(&rate).unwrap();
     let rev = out.mul_floor(&rate);
     println!("{amount:?} {out:?} {rev:?}");
     assert_eq!(amount, rev);
 
     let amount = AmountU128::new(Uint128::from(1_000_000u128));
     let rate = Rate::new_precise(Decimal::percent(50), &precise_a, &precise_b).unwrap();
-    let out = amount.div_floor(&rate);
+    let out = amount.div_floor(&rate).unwrap();
     let rev = out.mul_floor(&rate);
     println!("{amount:?} {out:?} {rev:?}");
     assert_eq!(amount, rev);
@@ -40,6 +40,19 @@ pub struct A;
 #[denom]
 pub struct B;
 
+#[denom]
+pub struct Atom;
+
+/// An amount of [`Usd`], for tests that only need one currency.
+pub(crate) fn amount(value: u128) -> AmountU128<Usd> {
+    AmountU128::new(Uint128::new(value))
+}
+
+/// The price of [`Atom`] in [`Usd`], given in percent.
+pub(crate) fn rate(percent: u64) -> Rate<Usd, Atom> {
+    Rate::new(Decimal::percent(percent)).unwrap()
+}
+
 #[allow(dead_code, unused)]
 #[test]
 fn type_checked_currency() {
@@ -48,7 +61,7 @@ fn type_checked_currency() {
     let b_rate = oracle_rate_b(); // B = $0.7
 
     // Calculate rate from A to B
-    let rate_a_to_b = b_rate.inv() * a_rate;
+    let rate_a_to_b = b_rate.inv().unwrap() * a_rate;
 
     // Convert amount from A to B
     let amount_b = amount_a.mul_floor(&rate_a_to_b);
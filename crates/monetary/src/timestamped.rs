@@ -0,0 +1,174 @@
+use std::ops::Mul;
+
+use cosmwasm_std::{Env, Timestamp};
+
+use crate::{MonetaryError, Rate};
+
+/// A [`Rate`] along with when it was observed, e.g. an oracle price.
+///
+/// Composing timestamped rates keeps the oldest observation, so a derived rate is never
+/// considered fresher than its stalest input.
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = ""))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TimestampedRate<A, B> {
+    pub rate: Rate<A, B>,
+    pub time: Timestamp,
+    pub height: u64,
+}
+
+impl<A, B> TimestampedRate<A, B> {
+    pub fn new(rate: Rate<A, B>, time: Timestamp, height: u64) -> Self {
+        TimestampedRate { rate, time, height }
+    }
+
+    /// Creates a rate observed in the current block.
+    pub fn now(rate: Rate<A, B>, env: &Env) -> Self {
+        Self::new(rate, env.block.time, env.block.height)
+    }
+
+    /// Errors if the rate was observed more than `max_age` seconds before the current block.
+    pub fn ensure_fresh(&self, env: &Env, max_age: u64) -> Result<&Rate<A, B>, MonetaryError> {
+        let age = env.block.time.seconds().saturating_sub(self.time.seconds());
+        if age > max_age {
+            return Err(MonetaryError::StaleRate {
+                time: self.time.to_string(),
+                age,
+                max_age,
+            });
+        }
+        Ok(&self.rate)
+    }
+
+    /// Errors if the rate is outside of `min..=max`.
+    pub fn ensure_within(
+        &self,
+        min: &Rate<A, B>,
+        max: &Rate<A, B>,
+    ) -> Result<&Rate<A, B>, MonetaryError> {
+        let rate = self.rate.rate();
+        if rate < min.rate() || rate > max.rate() {
+            return Err(MonetaryError::RateOutOfBounds {
+                rate: self.rate.to_string(),
+                min: min.to_string(),
+                max: max.to_string(),
+            });
+        }
+        Ok(&self.rate)
+    }
+
+    /// Returns the inverse rate, observed at the same time.
+    pub fn inv(&self) -> Result<TimestampedRate<B, A>, MonetaryError> {
+        Ok(TimestampedRate::new(
+            self.rate.inv()?,
+            self.time,
+            self.height,
+        ))
+    }
+}
+
+impl<N, S> TimestampedRate<N, S> {
+    /// Composes the rates as [`Rate::checked_mul`], keeping the oldest observation.
+    pub fn checked_mul<D>(
+        self,
+        rhs: TimestampedRate<S, D>,
+    ) -> Result<TimestampedRate<N, D>, MonetaryError> {
+        Ok(TimestampedRate::new(
+            self.rate.checked_mul(rhs.rate)?,
+            self.time.min(rhs.time),
+            self.height.min(rhs.height),
+        ))
+    }
+}
+
+/// # Panics
+/// Panics if the product overflows or rounds to zero. Use [`TimestampedRate::checked_mul`] to
+/// handle these cases.
+impl<N, S, D> Mul<TimestampedRate<S, D>> for TimestampedRate<N, S> {
+    type Output = TimestampedRate<N, D>;
+
+    fn mul(self, rhs: TimestampedRate<S, D>) -> Self::Output {
+        self.checked_mul(rhs).unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::{testing::mock_env, Decimal, Timestamp};
+    use monetary_macros::denom;
+
+    use crate::{MonetaryError, Rate, TimestampedRate};
+
+    #[denom]
+    pub struct Usd;
+
+    #[denom]
+    pub struct Atom;
+
+    #[denom]
+    pub struct Osmo;
+
+    #[test]
+    fn freshness() {
+        let env = mock_env();
+        let rate = Rate::<Usd, Atom>::new(Decimal::percent(1000)).unwrap();
+        let observed = TimestampedRate::new(rate, env.block.time.minus_seconds(60), 100);
+
+        assert_eq!(observed.ensure_fresh(&env, 60).unwrap(), &rate);
+        assert_eq!(
+            observed.ensure_fresh(&env, 59).unwrap_err(),
+            MonetaryError::StaleRate {
+                time: observed.time.to_string(),
+                age: 60,
+                max_age: 59,
+            }
+        );
+        TimestampedRate::now(rate, &env)
+            .ensure_fresh(&env, 0)
+            .unwrap();
+    }
+
+    #[test]
+    fn bounds() {
+        let rate = |percent| Rate::<Usd, Atom>::new(Decimal::percent(percent)).unwrap();
+        let observed = TimestampedRate::new(rate(1000), Timestamp::from_seconds(1), 1);
+
+        observed.ensure_within(&rate(1000), &rate(1000)).unwrap();
+        observed.ensure_within(&rate(500), &rate(1500)).unwrap();
+        assert_eq!(
+            observed
+                .ensure_within(&rate(1100), &rate(1500))
+                .unwrap_err(),
+            MonetaryError::RateOutOfBounds {
+                rate: "10".to_string(),
+                min: "11".to_string(),
+                max: "15".to_string(),
+            }
+        );
+        observed.ensure_within(&rate(500), &rate(900)).unwrap_err();
+    }
+
+    #[test]
+    fn composition_keeps_oldest() {
+        let usd_per_atom = TimestampedRate::new(
+            Rate::<Usd, Atom>::new(Decimal::percent(1000)).unwrap(),
+            Timestamp::from_seconds(200),
+            20,
+        );
+        let atom_per_osmo = TimestampedRate::new(
+            Rate::<Atom, Osmo>::new(Decimal::percent(5)).unwrap(),
+            Timestamp::from_seconds(100),
+            10,
+        );
+
+        let usd_per_osmo = usd_per_atom * atom_per_osmo;
+        assert_eq!(usd_per_osmo.rate.rate(), Decimal::percent(50));
+        assert_eq!(usd_per_osmo.time, Timestamp::from_seconds(100));
+        assert_eq!(usd_per_osmo.height, 10);
+
+        let osmo_per_usd = usd_per_osmo.inv().unwrap();
+        assert_eq!(osmo_per_usd.rate.rate(), Decimal::percent(200));
+        assert_eq!(osmo_per_usd.time, Timestamp::from_seconds(100));
+    }
+}